@@ -1,6 +1,6 @@
 use crate::grit_context::GritExecContext;
 use crate::{grit_binding::GritBinding, grit_context::GritQueryContext};
-use anyhow::Result;
+use anyhow::{bail, Result};
 use grit_pattern_matcher::constant::Constant;
 use grit_pattern_matcher::effects::Effect;
 use grit_pattern_matcher::pattern::{
@@ -12,6 +12,11 @@ use im::Vector;
 use std::borrow::Cow;
 use std::collections::HashMap;
 
+/// Foreign (JavaScript) functions can't be called from Biome's Grit engine, so their calls
+/// are reported as errors instead of being resolved.
+const FOREIGN_FUNCTIONS_UNSUPPORTED: &str =
+    "foreign function calls are not supported in this context";
+
 #[derive(Clone, Debug, PartialEq)]
 pub(crate) struct GritResolvedPattern;
 
@@ -54,12 +59,15 @@ impl<'a> ResolvedPattern<'a, GritQueryContext> for GritResolvedPattern {
     }
 
     fn from_dynamic_pattern(
-        _pattern: &'a DynamicPattern<GritQueryContext>,
+        pattern: &'a DynamicPattern<GritQueryContext>,
         _state: &mut State<'a, GritQueryContext>,
         _context: &'a GritExecContext,
         _logs: &mut grit_util::AnalysisLogs,
     ) -> anyhow::Result<Self> {
-        todo!()
+        match pattern {
+            DynamicPattern::CallForeignFunction(_) => bail!(FOREIGN_FUNCTIONS_UNSUPPORTED),
+            _ => todo!(),
+        }
     }
 
     fn from_accessor(
@@ -81,12 +89,15 @@ impl<'a> ResolvedPattern<'a, GritQueryContext> for GritResolvedPattern {
     }
 
     fn from_pattern(
-        _pattern: &'a Pattern<GritQueryContext>,
+        pattern: &'a Pattern<GritQueryContext>,
         _state: &mut State<'a, GritQueryContext>,
         _context: &'a GritExecContext,
         _logs: &mut grit_util::AnalysisLogs,
     ) -> anyhow::Result<Self> {
-        todo!()
+        match pattern {
+            Pattern::CallForeignFunction(_) => bail!(FOREIGN_FUNCTIONS_UNSUPPORTED),
+            _ => todo!(),
+        }
     }
 
     fn extend(
@@ -271,3 +282,33 @@ impl<'a> Iterator for TodoSnippetIterator<'a> {
         todo!()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use grit_pattern_matcher::pattern::CallForeignFunction;
+
+    #[test]
+    fn foreign_function_calls_are_reported_as_errors() {
+        let pattern =
+            Pattern::CallForeignFunction(Box::new(CallForeignFunction::new(0, Vec::new())));
+        let dynamic_pattern =
+            DynamicPattern::CallForeignFunction(CallForeignFunction::new(0, Vec::new()));
+        let context = GritExecContext;
+        let mut state = State::new(Vector::new(), Vec::new());
+        let mut logs: AnalysisLogs = Vec::new().into();
+
+        let error = GritResolvedPattern::from_pattern(&pattern, &mut state, &context, &mut logs)
+            .unwrap_err();
+        assert_eq!(error.to_string(), FOREIGN_FUNCTIONS_UNSUPPORTED);
+
+        let error = GritResolvedPattern::from_dynamic_pattern(
+            &dynamic_pattern,
+            &mut state,
+            &context,
+            &mut logs,
+        )
+        .unwrap_err();
+        assert_eq!(error.to_string(), FOREIGN_FUNCTIONS_UNSUPPORTED);
+    }
+}
@@ -68,8 +68,9 @@ impl TryFrom<&Path> for HtmlFileSource {
     fn try_from(path: &Path) -> Result<Self, Self::Error> {
         let file_name = path
             .file_name()
-            .and_then(OsStr::to_str)
-            .ok_or_else(|| FileSourceError::MissingFileName(path.into()))?;
+            .ok_or_else(|| FileSourceError::MissingFileName(path.into()))?
+            .to_str()
+            .ok_or_else(|| FileSourceError::NonUtf8FileName(path.into()))?;
 
         if let Ok(file_source) = Self::try_from_well_known(file_name) {
             return Ok(file_source);
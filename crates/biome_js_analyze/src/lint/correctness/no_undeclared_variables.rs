@@ -93,7 +93,7 @@ impl Rule for NoUndeclaredVariables {
 
 fn is_global(reference_name: &str, source_type: &JsFileSource) -> bool {
     match source_type.language() {
-        Language::JavaScript => is_js_global(reference_name),
+        Language::JavaScript | Language::Flow => is_js_global(reference_name),
         Language::TypeScript { .. } => is_js_global(reference_name) || is_ts_global(reference_name),
     }
 }
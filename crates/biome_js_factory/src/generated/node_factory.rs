@@ -6426,6 +6426,15 @@ pub fn ts_type_parameters(
         ],
     ))
 }
+pub fn ts_type_snipped(ty: AnyTsType, eof_token: SyntaxToken) -> TsTypeSnipped {
+    TsTypeSnipped::unwrap_cast(SyntaxNode::new_detached(
+        JsSyntaxKind::TS_TYPE_SNIPPED,
+        [
+            Some(SyntaxElement::Node(ty.into_syntax())),
+            Some(SyntaxElement::Token(eof_token)),
+        ],
+    ))
+}
 pub fn ts_typeof_type(
     typeof_token: SyntaxToken,
     expression_name: AnyTsName,
@@ -611,6 +611,7 @@ fn handle_root_comments(comment: DecoratedComment<JsLanguage>) -> CommentPlaceme
             AnyJsRoot::JsScript(script) => {
                 script.directives().is_empty() && script.statements().is_empty()
             }
+            AnyJsRoot::TsTypeSnipped(_) => false,
         };
 
         if is_blank {
@@ -10190,6 +10190,42 @@ impl IntoFormat<JsFormatContext> for biome_js_syntax::TsTypeParameters {
         )
     }
 }
+impl FormatRule<biome_js_syntax::TsTypeSnipped>
+    for crate::ts::auxiliary::type_snipped::FormatTsTypeSnipped
+{
+    type Context = JsFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_js_syntax::TsTypeSnipped, f: &mut JsFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_js_syntax::TsTypeSnipped>::fmt(self, node, f)
+    }
+}
+impl AsFormat<JsFormatContext> for biome_js_syntax::TsTypeSnipped {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_js_syntax::TsTypeSnipped,
+        crate::ts::auxiliary::type_snipped::FormatTsTypeSnipped,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::ts::auxiliary::type_snipped::FormatTsTypeSnipped::default(),
+        )
+    }
+}
+impl IntoFormat<JsFormatContext> for biome_js_syntax::TsTypeSnipped {
+    type Format = FormatOwnedWithRule<
+        biome_js_syntax::TsTypeSnipped,
+        crate::ts::auxiliary::type_snipped::FormatTsTypeSnipped,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::ts::auxiliary::type_snipped::FormatTsTypeSnipped::default(),
+        )
+    }
+}
 impl FormatRule<biome_js_syntax::TsTypeofType>
     for crate::ts::types::typeof_type::FormatTsTypeofType
 {
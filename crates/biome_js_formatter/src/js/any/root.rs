@@ -11,6 +11,7 @@ impl FormatRule<AnyJsRoot> for FormatAnyJsRoot {
             AnyJsRoot::JsExpressionSnipped(node) => node.format().fmt(f),
             AnyJsRoot::JsModule(node) => node.format().fmt(f),
             AnyJsRoot::JsScript(node) => node.format().fmt(f),
+            AnyJsRoot::TsTypeSnipped(node) => node.format().fmt(f),
         }
     }
 }
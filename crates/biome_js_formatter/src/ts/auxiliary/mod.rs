@@ -39,3 +39,4 @@ pub(crate) mod template_element;
 pub(crate) mod type_annotation;
 pub(crate) mod type_constraint_clause;
 pub(crate) mod type_parameter_name;
+pub(crate) mod type_snipped;
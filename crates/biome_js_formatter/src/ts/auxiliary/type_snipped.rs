@@ -0,0 +1,16 @@
+use crate::prelude::*;
+
+use biome_formatter::write;
+use biome_js_syntax::TsTypeSnipped;
+use biome_js_syntax::TsTypeSnippedFields;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTsTypeSnipped;
+
+impl FormatNodeRule<TsTypeSnipped> for FormatTsTypeSnipped {
+    fn fmt_fields(&self, node: &TsTypeSnipped, f: &mut JsFormatter) -> FormatResult<()> {
+        let TsTypeSnippedFields { ty, eof_token } = node.as_fields();
+
+        write![f, [ty.format(), format_removed(&eof_token?),]]
+    }
+}
@@ -0,0 +1,2222 @@
+//! Conversion of JavaScript syntax trees to [ESTree](https://github.com/estree/estree) shaped JSON.
+//!
+//! The output follows the shape produced by Acorn: every node has a `type`, `start` and `end`
+//! offsets, a `range`, and a `loc` with one-based lines and zero-based columns. Offsets and
+//! columns count UTF-16 code units, like JavaScript strings do.
+
+use biome_js_syntax::numbers::parse_js_number;
+use biome_js_syntax::{
+    AnyJsArrayAssignmentPatternElement, AnyJsArrayBindingPatternElement, AnyJsArrayElement,
+    AnyJsArrowFunctionParameters, AnyJsAssignment, AnyJsAssignmentPattern, AnyJsBinding,
+    AnyJsBindingPattern, AnyJsCallArgument, AnyJsClassMember, AnyJsClassMemberName,
+    AnyJsCombinedSpecifier, AnyJsConstructorParameter, AnyJsDeclarationClause, AnyJsExportClause,
+    AnyJsExportDefaultDeclaration, AnyJsExportNamedSpecifier, AnyJsExpression,
+    AnyJsForInOrOfInitializer, AnyJsForInitializer, AnyJsFormalParameter, AnyJsFunctionBody,
+    AnyJsImportAssertionEntry, AnyJsImportClause, AnyJsInProperty, AnyJsLiteralExpression,
+    AnyJsModuleItem, AnyJsName, AnyJsNamedImportSpecifier, AnyJsObjectAssignmentPatternMember,
+    AnyJsObjectBindingPatternMember, AnyJsObjectMember, AnyJsObjectMemberName, AnyJsParameter,
+    AnyJsRoot, AnyJsStatement, AnyJsSwitchClause, AnyJsTemplateElement, JsCallArguments,
+    JsCatchClause, JsClassMemberList, JsDecoratorList, JsDirective, JsDirectiveList,
+    JsExtendsClause, JsFunctionBody, JsImportAssertion, JsInitializerClause, JsLanguage,
+    JsLiteralExportName, JsModuleSource, JsParameters, JsReferenceIdentifier, JsSpread,
+    JsStatementList, JsSyntaxKind, JsSyntaxToken, JsTemplateExpression, JsVariableDeclaration,
+    JsVariableDeclarator, TextRange, TextSize, T,
+};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, SyntaxError};
+use serde_json::{json, Map, Value};
+use std::fmt;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Converts `root` to an ESTree `Program`.
+///
+/// Returns an error for trees with syntax errors, and for TypeScript, JSX, and decorators,
+/// which have no representation in ESTree.
+///
+/// ```
+/// use biome_js_parser::estree::to_estree;
+/// use biome_js_parser::{parse_module, JsParserOptions};
+/// use biome_js_syntax::AnyJsRoot;
+///
+/// let parse = parse_module("a;", JsParserOptions::default());
+/// let program = to_estree(&AnyJsRoot::from(parse.tree())).unwrap();
+///
+/// assert_eq!(program["type"], "Program");
+/// assert_eq!(program["body"][0]["expression"]["name"], "a");
+/// assert_eq!(program["body"][0]["range"], serde_json::json!([0, 2]));
+/// ```
+pub fn to_estree(root: &AnyJsRoot) -> Result<Value, EstreeError> {
+    let text = root.syntax().text().to_string();
+    let converter = Converter {
+        locations: Locations::new(&text, root.syntax().text_range().start()),
+    };
+
+    converter.root(root)
+}
+
+/// The reason why a tree can't be converted to ESTree.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum EstreeError {
+    /// A required child of a node is missing because the source has syntax errors.
+    MissingChild,
+    /// The node has no ESTree representation, e.g. TypeScript syntax or a bogus node.
+    Unsupported {
+        kind: JsSyntaxKind,
+        range: TextRange,
+    },
+}
+
+impl fmt::Display for EstreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EstreeError::MissingChild => f.write_str("the syntax tree is missing a required child"),
+            EstreeError::Unsupported { kind, range } => {
+                write!(f, "{kind:?} at {range:?} has no ESTree representation")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EstreeError {}
+
+impl From<SyntaxError> for EstreeError {
+    fn from(_: SyntaxError) -> Self {
+        EstreeError::MissingChild
+    }
+}
+
+type ConvertResult = Result<Value, EstreeError>;
+
+fn unsupported(node: &impl AstNode<Language = JsLanguage>) -> EstreeError {
+    EstreeError::Unsupported {
+        kind: node.syntax().kind(),
+        range: node.range(),
+    }
+}
+
+/// Fails for TypeScript-only children like type annotations.
+fn reject<N: AstNode<Language = JsLanguage>>(node: Option<N>) -> Result<(), EstreeError> {
+    match node {
+        Some(node) => Err(unsupported(&node)),
+        None => Ok(()),
+    }
+}
+
+fn reject_decorators(decorators: &JsDecoratorList) -> Result<(), EstreeError> {
+    reject(decorators.first())
+}
+
+/// Maps UTF-8 text offsets to UTF-16 offsets, lines, and columns.
+struct Locations<'a> {
+    text: &'a str,
+    base: TextSize,
+    /// The UTF-8 and UTF-16 offsets of the start of each line
+    line_starts: Vec<(usize, usize)>,
+}
+
+impl<'a> Locations<'a> {
+    fn new(text: &'a str, base: TextSize) -> Self {
+        let mut line_starts = vec![(0, 0)];
+        let mut utf16_offset = 0;
+        let mut chars = text.char_indices().peekable();
+
+        while let Some((offset, c)) = chars.next() {
+            utf16_offset += c.len_utf16();
+            let is_line_break = match c {
+                '\r' => chars.peek().map(|(_, next)| *next) != Some('\n'),
+                '\n' | '\u{2028}' | '\u{2029}' => true,
+                _ => false,
+            };
+
+            if is_line_break {
+                line_starts.push((offset + c.len_utf8(), utf16_offset));
+            }
+        }
+
+        Self {
+            text,
+            base,
+            line_starts,
+        }
+    }
+
+    /// Returns the UTF-16 offset, the one-based line, and the column of `offset`.
+    fn position(&self, offset: TextSize) -> (usize, usize, usize) {
+        let offset = usize::from(offset - self.base);
+        let line = self
+            .line_starts
+            .partition_point(|(start, _)| *start <= offset)
+            - 1;
+        let (line_start, utf16_line_start) = self.line_starts[line];
+        let column = self.text[line_start..offset].encode_utf16().count();
+
+        (utf16_line_start + column, line + 1, column)
+    }
+}
+
+struct Converter<'a> {
+    locations: Locations<'a>,
+}
+
+impl Converter<'_> {
+    /// Creates a node of type `kind` spanning `range` with the properties of `fields`.
+    fn node(&self, kind: &str, range: TextRange, fields: Value) -> Value {
+        let (start, start_line, start_column) = self.locations.position(range.start());
+        let (end, end_line, end_column) = self.locations.position(range.end());
+
+        let mut node = Map::new();
+        node.insert("type".to_string(), kind.into());
+        node.insert("start".to_string(), start.into());
+        node.insert("end".to_string(), end.into());
+        node.insert(
+            "loc".to_string(),
+            json!({
+                "start": { "line": start_line, "column": start_column },
+                "end": { "line": end_line, "column": end_column },
+            }),
+        );
+        node.insert("range".to_string(), json!([start, end]));
+
+        if let Value::Object(fields) = fields {
+            node.extend(fields);
+        }
+
+        Value::Object(node)
+    }
+
+    fn root(&self, root: &AnyJsRoot) -> ConvertResult {
+        let (source_type, body) = match root {
+            AnyJsRoot::JsModule(module) => {
+                let mut body = self.directives(&module.directives())?;
+                for item in module.items().iter() {
+                    body.push(self.module_item(&item)?);
+                }
+                ("module", body)
+            }
+            AnyJsRoot::JsScript(script) => {
+                let mut body = self.directives(&script.directives())?;
+                body.extend(self.statements(&script.statements())?);
+                ("script", body)
+            }
+            AnyJsRoot::JsExpressionSnipped(_) | AnyJsRoot::TsTypeSnipped(_) => {
+                return Err(unsupported(root))
+            }
+        };
+
+        Ok(self.node(
+            "Program",
+            root.syntax().text_range(),
+            json!({ "sourceType": source_type, "body": body }),
+        ))
+    }
+
+    fn directives(&self, directives: &JsDirectiveList) -> Result<Vec<Value>, EstreeError> {
+        directives
+            .iter()
+            .map(|directive| self.directive(&directive))
+            .collect()
+    }
+
+    fn directive(&self, directive: &JsDirective) -> ConvertResult {
+        let token = directive.value_token()?;
+        let text = token.text_trimmed();
+
+        Ok(self.node(
+            "ExpressionStatement",
+            directive.range(),
+            json!({
+                "expression": self.string_literal(&token),
+                "directive": &text[1..text.len() - 1],
+            }),
+        ))
+    }
+
+    fn module_item(&self, item: &AnyJsModuleItem) -> ConvertResult {
+        match item {
+            AnyJsModuleItem::AnyJsStatement(statement) => self.statement(statement),
+            AnyJsModuleItem::JsImport(import) => {
+                let (specifiers, source, assertion) = match import.import_clause()? {
+                    AnyJsImportClause::JsImportBareClause(clause) => {
+                        (Vec::new(), clause.source()?, clause.assertion())
+                    }
+                    AnyJsImportClause::JsImportDefaultClause(clause) => {
+                        reject(clause.type_token().map(|_| clause.clone()))?;
+                        let specifier = clause.default_specifier()?;
+                        let default = self.node(
+                            "ImportDefaultSpecifier",
+                            specifier.range(),
+                            json!({ "local": self.binding(&specifier.local_name()?)? }),
+                        );
+                        (vec![default], clause.source()?, clause.assertion())
+                    }
+                    AnyJsImportClause::JsImportNamedClause(clause) => {
+                        reject(clause.type_token().map(|_| clause.clone()))?;
+                        let specifiers = self.combined_specifier(&AnyJsCombinedSpecifier::from(
+                            clause.named_specifiers()?,
+                        ))?;
+                        (specifiers, clause.source()?, clause.assertion())
+                    }
+                    AnyJsImportClause::JsImportNamespaceClause(clause) => {
+                        reject(clause.type_token().map(|_| clause.clone()))?;
+                        let specifiers = self.combined_specifier(&AnyJsCombinedSpecifier::from(
+                            clause.namespace_specifier()?,
+                        ))?;
+                        (specifiers, clause.source()?, clause.assertion())
+                    }
+                    AnyJsImportClause::JsImportCombinedClause(clause) => {
+                        let specifier = clause.default_specifier()?;
+                        let mut specifiers = vec![self.node(
+                            "ImportDefaultSpecifier",
+                            specifier.range(),
+                            json!({ "local": self.binding(&specifier.local_name()?)? }),
+                        )];
+                        specifiers.extend(self.combined_specifier(&clause.specifier()?)?);
+                        (specifiers, clause.source()?, clause.assertion())
+                    }
+                };
+
+                Ok(self.node(
+                    "ImportDeclaration",
+                    import.range(),
+                    json!({
+                        "specifiers": specifiers,
+                        "source": self.module_source(&source)?,
+                        "attributes": self.import_attributes(assertion)?,
+                    }),
+                ))
+            }
+            AnyJsModuleItem::JsExport(export) => {
+                reject_decorators(&export.decorators())?;
+                self.export(export.range(), &export.export_clause()?)
+            }
+        }
+    }
+
+    fn combined_specifier(
+        &self,
+        specifier: &AnyJsCombinedSpecifier,
+    ) -> Result<Vec<Value>, EstreeError> {
+        match specifier {
+            AnyJsCombinedSpecifier::JsNamedImportSpecifiers(specifiers) => specifiers
+                .specifiers()
+                .iter()
+                .map(|specifier| match specifier? {
+                    AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+                        reject(specifier.type_token().map(|_| specifier.clone()))?;
+                        Ok(self.node(
+                            "ImportSpecifier",
+                            specifier.range(),
+                            json!({
+                                "imported": self.export_name(&specifier.name()?)?,
+                                "local": self.binding(&specifier.local_name()?)?,
+                            }),
+                        ))
+                    }
+                    AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+                        reject(specifier.type_token().map(|_| specifier.clone()))?;
+                        let local = self.binding(&specifier.local_name()?)?;
+                        Ok(self.node(
+                            "ImportSpecifier",
+                            specifier.range(),
+                            json!({ "imported": local.clone(), "local": local }),
+                        ))
+                    }
+                    specifier @ AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => {
+                        Err(unsupported(&specifier))
+                    }
+                })
+                .collect(),
+            AnyJsCombinedSpecifier::JsNamespaceImportSpecifier(specifier) => Ok(vec![self.node(
+                "ImportNamespaceSpecifier",
+                specifier.range(),
+                json!({ "local": self.binding(&specifier.local_name()?)? }),
+            )]),
+        }
+    }
+
+    fn import_attributes(&self, assertion: Option<JsImportAssertion>) -> ConvertResult {
+        let Some(assertion) = assertion else {
+            return Ok(json!([]));
+        };
+
+        let attributes = assertion
+            .assertions()
+            .iter()
+            .map(|entry| match entry? {
+                AnyJsImportAssertionEntry::JsImportAssertionEntry(entry) => {
+                    let key = entry.key()?;
+                    let key = if key.kind() == JsSyntaxKind::JS_STRING_LITERAL {
+                        self.string_literal(&key)
+                    } else {
+                        self.identifier(&key)
+                    };
+
+                    Ok(self.node(
+                        "ImportAttribute",
+                        entry.range(),
+                        json!({ "key": key, "value": self.string_literal(&entry.value_token()?) }),
+                    ))
+                }
+                entry @ AnyJsImportAssertionEntry::JsBogusImportAssertionEntry(_) => {
+                    Err(unsupported(&entry))
+                }
+            })
+            .collect::<Result<Vec<_>, EstreeError>>()?;
+
+        Ok(Value::Array(attributes))
+    }
+
+    fn export(&self, range: TextRange, clause: &AnyJsExportClause) -> ConvertResult {
+        let named =
+            |declaration: Value, specifiers: Vec<Value>, source: Value, attributes: Value| {
+                self.node(
+                    "ExportNamedDeclaration",
+                    range,
+                    json!({
+                        "declaration": declaration,
+                        "specifiers": specifiers,
+                        "source": source,
+                        "attributes": attributes,
+                    }),
+                )
+            };
+
+        match clause {
+            AnyJsExportClause::AnyJsDeclarationClause(declaration) => {
+                let declaration = match declaration {
+                    AnyJsDeclarationClause::JsClassDeclaration(declaration) => {
+                        self.statement(&AnyJsStatement::from(declaration.clone()))?
+                    }
+                    AnyJsDeclarationClause::JsFunctionDeclaration(declaration) => {
+                        self.statement(&AnyJsStatement::from(declaration.clone()))?
+                    }
+                    AnyJsDeclarationClause::JsVariableDeclarationClause(clause) => {
+                        self.variable_declaration(&clause.declaration()?, clause.range())?
+                    }
+                    _ => return Err(unsupported(declaration)),
+                };
+
+                Ok(named(declaration, Vec::new(), Value::Null, json!([])))
+            }
+            AnyJsExportClause::JsExportDefaultDeclarationClause(clause) => {
+                let declaration = match clause.declaration()? {
+                    AnyJsExportDefaultDeclaration::JsClassExportDefaultDeclaration(class) => {
+                        reject_decorators(&class.decorators())?;
+                        reject(class.abstract_token().map(|_| class.clone()))?;
+                        reject(class.type_parameters())?;
+                        reject(class.implements_clause())?;
+                        self.class(
+                            "ClassDeclaration",
+                            class.range(),
+                            class.id(),
+                            class.extends_clause(),
+                            &class.members(),
+                            class.l_curly_token()?,
+                            class.r_curly_token()?,
+                        )?
+                    }
+                    AnyJsExportDefaultDeclaration::JsFunctionExportDefaultDeclaration(function) => {
+                        reject(function.type_parameters())?;
+                        reject(function.return_type_annotation())?;
+                        self.function(
+                            "FunctionDeclaration",
+                            function.range(),
+                            function.id(),
+                            function.async_token().is_some(),
+                            function.star_token().is_some(),
+                            &function.parameters()?,
+                            &function.body()?,
+                        )?
+                    }
+                    declaration => return Err(unsupported(&declaration)),
+                };
+
+                Ok(self.node(
+                    "ExportDefaultDeclaration",
+                    range,
+                    json!({ "declaration": declaration }),
+                ))
+            }
+            AnyJsExportClause::JsExportDefaultExpressionClause(clause) => Ok(self.node(
+                "ExportDefaultDeclaration",
+                range,
+                json!({ "declaration": self.expression(&clause.expression()?)? }),
+            )),
+            AnyJsExportClause::JsExportFromClause(clause) => {
+                reject(clause.type_token().map(|_| clause.clone()))?;
+                let exported = match clause.export_as() {
+                    Some(export_as) => self.export_name(&export_as.exported_name()?)?,
+                    None => Value::Null,
+                };
+
+                Ok(self.node(
+                    "ExportAllDeclaration",
+                    range,
+                    json!({
+                        "exported": exported,
+                        "source": self.module_source(&clause.source()?)?,
+                        "attributes": self.import_attributes(clause.assertion())?,
+                    }),
+                ))
+            }
+            AnyJsExportClause::JsExportNamedClause(clause) => {
+                reject(clause.type_token().map(|_| clause.clone()))?;
+                let specifiers = clause
+                    .specifiers()
+                    .iter()
+                    .map(|specifier| {
+                        let specifier = specifier?;
+                        let (local, exported) = match &specifier {
+                            AnyJsExportNamedSpecifier::JsExportNamedShorthandSpecifier(
+                                shorthand,
+                            ) => {
+                                reject(shorthand.type_token().map(|_| shorthand.clone()))?;
+                                let local = self.reference(&shorthand.name()?)?;
+                                (local.clone(), local)
+                            }
+                            AnyJsExportNamedSpecifier::JsExportNamedSpecifier(named) => {
+                                reject(named.type_token().map(|_| named.clone()))?;
+                                (
+                                    self.reference(&named.local_name()?)?,
+                                    self.export_name(&named.exported_name()?)?,
+                                )
+                            }
+                        };
+
+                        Ok(self.node(
+                            "ExportSpecifier",
+                            specifier.range(),
+                            json!({ "local": local, "exported": exported }),
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, EstreeError>>()?;
+
+                Ok(named(Value::Null, specifiers, Value::Null, json!([])))
+            }
+            AnyJsExportClause::JsExportNamedFromClause(clause) => {
+                reject(clause.type_token().map(|_| clause.clone()))?;
+                let specifiers = clause
+                    .specifiers()
+                    .iter()
+                    .map(|specifier| {
+                        let specifier = specifier?;
+                        reject(specifier.type_token().map(|_| specifier.clone()))?;
+                        let local = self.export_name(&specifier.source_name()?)?;
+                        let exported = match specifier.export_as() {
+                            Some(export_as) => self.export_name(&export_as.exported_name()?)?,
+                            None => local.clone(),
+                        };
+
+                        Ok(self.node(
+                            "ExportSpecifier",
+                            specifier.range(),
+                            json!({ "local": local, "exported": exported }),
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, EstreeError>>()?;
+
+                Ok(named(
+                    Value::Null,
+                    specifiers,
+                    self.module_source(&clause.source()?)?,
+                    self.import_attributes(clause.assertion())?,
+                ))
+            }
+            _ => Err(unsupported(clause)),
+        }
+    }
+
+    fn statements(&self, statements: &JsStatementList) -> Result<Vec<Value>, EstreeError> {
+        statements
+            .iter()
+            .map(|statement| self.statement(&statement))
+            .collect()
+    }
+
+    fn statement(&self, statement: &AnyJsStatement) -> ConvertResult {
+        let range = statement.range();
+
+        let statement = match statement {
+            AnyJsStatement::JsBlockStatement(block) => self.node(
+                "BlockStatement",
+                range,
+                json!({ "body": self.statements(&block.statements())? }),
+            ),
+            AnyJsStatement::JsBreakStatement(statement) => {
+                let label = match statement.label() {
+                    Some(label) => self.identifier(&label.value_token()?),
+                    None => Value::Null,
+                };
+                self.node("BreakStatement", range, json!({ "label": label }))
+            }
+            AnyJsStatement::JsContinueStatement(statement) => {
+                let label = match statement.label() {
+                    Some(label) => self.identifier(&label.value_token()?),
+                    None => Value::Null,
+                };
+                self.node("ContinueStatement", range, json!({ "label": label }))
+            }
+            AnyJsStatement::JsClassDeclaration(class) => {
+                reject_decorators(&class.decorators())?;
+                reject(class.abstract_token().map(|_| class.clone()))?;
+                reject(class.type_parameters())?;
+                reject(class.implements_clause())?;
+                self.class(
+                    "ClassDeclaration",
+                    range,
+                    Some(class.id()?),
+                    class.extends_clause(),
+                    &class.members(),
+                    class.l_curly_token()?,
+                    class.r_curly_token()?,
+                )?
+            }
+            AnyJsStatement::JsDebuggerStatement(_) => {
+                self.node("DebuggerStatement", range, json!({}))
+            }
+            AnyJsStatement::JsDoWhileStatement(statement) => self.node(
+                "DoWhileStatement",
+                range,
+                json!({
+                    "body": self.statement(&statement.body()?)?,
+                    "test": self.expression(&statement.test()?)?,
+                }),
+            ),
+            AnyJsStatement::JsEmptyStatement(_) => self.node("EmptyStatement", range, json!({})),
+            AnyJsStatement::JsExpressionStatement(statement) => self.node(
+                "ExpressionStatement",
+                range,
+                json!({ "expression": self.expression(&statement.expression()?)? }),
+            ),
+            AnyJsStatement::JsForInStatement(statement) => self.node(
+                "ForInStatement",
+                range,
+                json!({
+                    "left": self.for_in_or_of_initializer(&statement.initializer()?)?,
+                    "right": self.expression(&statement.expression()?)?,
+                    "body": self.statement(&statement.body()?)?,
+                }),
+            ),
+            AnyJsStatement::JsForOfStatement(statement) => self.node(
+                "ForOfStatement",
+                range,
+                json!({
+                    "await": statement.await_token().is_some(),
+                    "left": self.for_in_or_of_initializer(&statement.initializer()?)?,
+                    "right": self.expression(&statement.expression()?)?,
+                    "body": self.statement(&statement.body()?)?,
+                }),
+            ),
+            AnyJsStatement::JsForStatement(statement) => {
+                let init = match statement.initializer() {
+                    Some(AnyJsForInitializer::AnyJsExpression(expression)) => {
+                        self.expression(&expression)?
+                    }
+                    Some(AnyJsForInitializer::JsVariableDeclaration(declaration)) => {
+                        self.variable_declaration(&declaration, declaration.range())?
+                    }
+                    None => Value::Null,
+                };
+
+                self.node(
+                    "ForStatement",
+                    range,
+                    json!({
+                        "init": init,
+                        "test": self.optional_expression(statement.test())?,
+                        "update": self.optional_expression(statement.update())?,
+                        "body": self.statement(&statement.body()?)?,
+                    }),
+                )
+            }
+            AnyJsStatement::JsFunctionDeclaration(function) => {
+                reject(function.type_parameters())?;
+                reject(function.return_type_annotation())?;
+                self.function(
+                    "FunctionDeclaration",
+                    range,
+                    Some(function.id()?),
+                    function.async_token().is_some(),
+                    function.star_token().is_some(),
+                    &function.parameters()?,
+                    &function.body()?,
+                )?
+            }
+            AnyJsStatement::JsIfStatement(statement) => {
+                let alternate = match statement.else_clause() {
+                    Some(else_clause) => self.statement(&else_clause.alternate()?)?,
+                    None => Value::Null,
+                };
+
+                self.node(
+                    "IfStatement",
+                    range,
+                    json!({
+                        "test": self.expression(&statement.test()?)?,
+                        "consequent": self.statement(&statement.consequent()?)?,
+                        "alternate": alternate,
+                    }),
+                )
+            }
+            AnyJsStatement::JsLabeledStatement(statement) => self.node(
+                "LabeledStatement",
+                range,
+                json!({
+                    "label": self.identifier(&statement.label()?.value_token()?),
+                    "body": self.statement(&statement.body()?)?,
+                }),
+            ),
+            AnyJsStatement::JsReturnStatement(statement) => self.node(
+                "ReturnStatement",
+                range,
+                json!({ "argument": self.optional_expression(statement.argument())? }),
+            ),
+            AnyJsStatement::JsSwitchStatement(statement) => {
+                let cases = statement
+                    .cases()
+                    .iter()
+                    .map(|case| {
+                        let (test, consequent) = match &case {
+                            AnyJsSwitchClause::JsCaseClause(case) => {
+                                (self.expression(&case.test()?)?, case.consequent())
+                            }
+                            AnyJsSwitchClause::JsDefaultClause(case) => {
+                                (Value::Null, case.consequent())
+                            }
+                        };
+
+                        Ok(self.node(
+                            "SwitchCase",
+                            case.range(),
+                            json!({ "test": test, "consequent": self.statements(&consequent)? }),
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, EstreeError>>()?;
+
+                self.node(
+                    "SwitchStatement",
+                    range,
+                    json!({
+                        "discriminant": self.expression(&statement.discriminant()?)?,
+                        "cases": cases,
+                    }),
+                )
+            }
+            AnyJsStatement::JsThrowStatement(statement) => self.node(
+                "ThrowStatement",
+                range,
+                json!({ "argument": self.expression(&statement.argument()?)? }),
+            ),
+            AnyJsStatement::JsTryStatement(statement) => self.node(
+                "TryStatement",
+                range,
+                json!({
+                    "block": self.statement(&AnyJsStatement::from(statement.body()?))?,
+                    "handler": self.catch_clause(Some(statement.catch_clause()?))?,
+                    "finalizer": null,
+                }),
+            ),
+            AnyJsStatement::JsTryFinallyStatement(statement) => {
+                let finalizer = statement.finally_clause()?.body()?;
+
+                self.node(
+                    "TryStatement",
+                    range,
+                    json!({
+                        "block": self.statement(&AnyJsStatement::from(statement.body()?))?,
+                        "handler": self.catch_clause(statement.catch_clause())?,
+                        "finalizer": self.statement(&AnyJsStatement::from(finalizer))?,
+                    }),
+                )
+            }
+            AnyJsStatement::JsVariableStatement(statement) => {
+                self.variable_declaration(&statement.declaration()?, range)?
+            }
+            AnyJsStatement::JsWhileStatement(statement) => self.node(
+                "WhileStatement",
+                range,
+                json!({
+                    "test": self.expression(&statement.test()?)?,
+                    "body": self.statement(&statement.body()?)?,
+                }),
+            ),
+            AnyJsStatement::JsWithStatement(statement) => self.node(
+                "WithStatement",
+                range,
+                json!({
+                    "object": self.expression(&statement.object()?)?,
+                    "body": self.statement(&statement.body()?)?,
+                }),
+            ),
+            _ => return Err(unsupported(statement)),
+        };
+
+        Ok(statement)
+    }
+
+    fn catch_clause(&self, clause: Option<JsCatchClause>) -> ConvertResult {
+        let Some(clause) = clause else {
+            return Ok(Value::Null);
+        };
+
+        let param = match clause.declaration() {
+            Some(declaration) => {
+                reject(declaration.type_annotation())?;
+                self.binding_pattern(&declaration.binding()?)?
+            }
+            None => Value::Null,
+        };
+
+        Ok(self.node(
+            "CatchClause",
+            clause.range(),
+            json!({
+                "param": param,
+                "body": self.statement(&AnyJsStatement::from(clause.body()?))?,
+            }),
+        ))
+    }
+
+    /// Converts a variable declaration, whose ESTree range includes the semicolon of the
+    /// statement that contains it.
+    fn variable_declaration(
+        &self,
+        declaration: &JsVariableDeclaration,
+        range: TextRange,
+    ) -> ConvertResult {
+        let declarations = declaration
+            .declarators()
+            .iter()
+            .map(|declarator| self.variable_declarator(&declarator?))
+            .collect::<Result<Vec<_>, EstreeError>>()?;
+
+        Ok(self.node(
+            "VariableDeclaration",
+            range,
+            json!({
+                "kind": variable_kind(declaration.await_token(), &declaration.kind()?),
+                "declarations": declarations,
+            }),
+        ))
+    }
+
+    fn variable_declarator(&self, declarator: &JsVariableDeclarator) -> ConvertResult {
+        reject(declarator.variable_annotation())?;
+        let init = match declarator.initializer() {
+            Some(initializer) => self.expression(&initializer.expression()?)?,
+            None => Value::Null,
+        };
+
+        Ok(self.node(
+            "VariableDeclarator",
+            declarator.range(),
+            json!({ "id": self.binding_pattern(&declarator.id()?)?, "init": init }),
+        ))
+    }
+
+    fn for_in_or_of_initializer(&self, initializer: &AnyJsForInOrOfInitializer) -> ConvertResult {
+        match initializer {
+            AnyJsForInOrOfInitializer::AnyJsAssignmentPattern(pattern) => {
+                self.assignment_pattern(pattern)
+            }
+            AnyJsForInOrOfInitializer::JsForVariableDeclaration(declaration) => Ok(self.node(
+                "VariableDeclaration",
+                declaration.range(),
+                json!({
+                    "kind": variable_kind(declaration.await_token(), &declaration.kind_token()?),
+                    "declarations": [self.variable_declarator(&declaration.declarator()?)?],
+                }),
+            )),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn function(
+        &self,
+        kind: &str,
+        range: TextRange,
+        id: Option<AnyJsBinding>,
+        is_async: bool,
+        is_generator: bool,
+        parameters: &JsParameters,
+        body: &JsFunctionBody,
+    ) -> ConvertResult {
+        let id = match id {
+            Some(id) => self.binding(&id)?,
+            None => Value::Null,
+        };
+
+        Ok(self.node(
+            kind,
+            range,
+            json!({
+                "id": id,
+                "expression": false,
+                "generator": is_generator,
+                "async": is_async,
+                "params": self.parameters(parameters)?,
+                "body": self.function_body(body)?,
+            }),
+        ))
+    }
+
+    fn function_body(&self, body: &JsFunctionBody) -> ConvertResult {
+        let mut statements = self.directives(&body.directives())?;
+        statements.extend(self.statements(&body.statements())?);
+
+        Ok(self.node(
+            "BlockStatement",
+            body.range(),
+            json!({ "body": statements }),
+        ))
+    }
+
+    fn parameters(&self, parameters: &JsParameters) -> ConvertResult {
+        let parameters = parameters
+            .items()
+            .iter()
+            .map(|parameter| match parameter? {
+                AnyJsParameter::AnyJsFormalParameter(parameter) => {
+                    self.formal_parameter(&parameter)
+                }
+                AnyJsParameter::JsRestParameter(parameter) => {
+                    reject_decorators(&parameter.decorators())?;
+                    reject(parameter.type_annotation())?;
+                    Ok(self.node(
+                        "RestElement",
+                        parameter.range(),
+                        json!({ "argument": self.binding_pattern(&parameter.binding()?)? }),
+                    ))
+                }
+                parameter @ AnyJsParameter::TsThisParameter(_) => Err(unsupported(&parameter)),
+            })
+            .collect::<Result<Vec<_>, EstreeError>>()?;
+
+        Ok(Value::Array(parameters))
+    }
+
+    fn formal_parameter(&self, parameter: &AnyJsFormalParameter) -> ConvertResult {
+        let AnyJsFormalParameter::JsFormalParameter(parameter) = parameter else {
+            return Err(unsupported(parameter));
+        };
+
+        reject_decorators(&parameter.decorators())?;
+        reject(parameter.question_mark_token().map(|_| parameter.clone()))?;
+        reject(parameter.type_annotation())?;
+
+        let binding = self.binding_pattern(&parameter.binding()?)?;
+        match parameter.initializer() {
+            Some(initializer) => Ok(self.node(
+                "AssignmentPattern",
+                parameter.range(),
+                json!({ "left": binding, "right": self.expression(&initializer.expression()?)? }),
+            )),
+            None => Ok(binding),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn class(
+        &self,
+        kind: &str,
+        range: TextRange,
+        id: Option<AnyJsBinding>,
+        extends_clause: Option<JsExtendsClause>,
+        members: &JsClassMemberList,
+        l_curly: JsSyntaxToken,
+        r_curly: JsSyntaxToken,
+    ) -> ConvertResult {
+        let id = match id {
+            Some(id) => self.binding(&id)?,
+            None => Value::Null,
+        };
+        let super_class = match extends_clause {
+            Some(extends_clause) => {
+                reject(extends_clause.type_arguments())?;
+                self.expression(&extends_clause.super_class()?)?
+            }
+            None => Value::Null,
+        };
+
+        let mut body = Vec::new();
+        for member in members.iter() {
+            if let Some(member) = self.class_member(&member)? {
+                body.push(member);
+            }
+        }
+
+        let body_range = TextRange::new(
+            l_curly.text_trimmed_range().start(),
+            r_curly.text_trimmed_range().end(),
+        );
+
+        Ok(self.node(
+            kind,
+            range,
+            json!({
+                "id": id,
+                "superClass": super_class,
+                "body": self.node("ClassBody", body_range, json!({ "body": body })),
+            }),
+        ))
+    }
+
+    /// Converts a class member, or returns `None` for a stray semicolon.
+    fn class_member(&self, member: &AnyJsClassMember) -> Result<Option<Value>, EstreeError> {
+        let range = member.range();
+
+        let member = match member {
+            AnyJsClassMember::JsConstructorClassMember(constructor) => {
+                reject(constructor.modifiers().first())?;
+                let name = constructor.name()?;
+                let parameters = constructor.parameters()?;
+                let parameter_list = parameters
+                    .parameters()
+                    .iter()
+                    .map(|parameter| match parameter? {
+                        AnyJsConstructorParameter::AnyJsFormalParameter(parameter) => {
+                            self.formal_parameter(&parameter)
+                        }
+                        AnyJsConstructorParameter::JsRestParameter(parameter) => {
+                            reject_decorators(&parameter.decorators())?;
+                            reject(parameter.type_annotation())?;
+                            Ok(self.node(
+                                "RestElement",
+                                parameter.range(),
+                                json!({ "argument": self.binding_pattern(&parameter.binding()?)? }),
+                            ))
+                        }
+                        parameter @ AnyJsConstructorParameter::TsPropertyParameter(_) => {
+                            Err(unsupported(&parameter))
+                        }
+                    })
+                    .collect::<Result<Vec<_>, EstreeError>>()?;
+                let body = constructor.body()?;
+                let value = self.node(
+                    "FunctionExpression",
+                    TextRange::new(parameters.range().start(), body.range().end()),
+                    json!({
+                        "id": null,
+                        "expression": false,
+                        "generator": false,
+                        "async": false,
+                        "params": parameter_list,
+                        "body": self.function_body(&body)?,
+                    }),
+                );
+
+                self.node(
+                    "MethodDefinition",
+                    range,
+                    json!({
+                        "kind": "constructor",
+                        "static": false,
+                        "computed": false,
+                        "key": self.literal_member_name(&name.value()?)?,
+                        "value": value,
+                    }),
+                )
+            }
+            AnyJsClassMember::JsEmptyClassMember(_) => return Ok(None),
+            AnyJsClassMember::JsGetterClassMember(getter) => {
+                reject(getter.return_type())?;
+                let body = getter.body()?;
+                let value = self.node(
+                    "FunctionExpression",
+                    TextRange::new(
+                        getter.l_paren_token()?.text_trimmed_range().start(),
+                        body.range().end(),
+                    ),
+                    json!({
+                        "id": null,
+                        "expression": false,
+                        "generator": false,
+                        "async": false,
+                        "params": [],
+                        "body": self.function_body(&body)?,
+                    }),
+                );
+
+                self.method_definition(
+                    range,
+                    "get",
+                    self.is_static(getter.modifiers().iter())?,
+                    &getter.name()?,
+                    value,
+                )?
+            }
+            AnyJsClassMember::JsMethodClassMember(method) => {
+                reject(method.question_mark_token().map(|_| method.clone()))?;
+                reject(method.type_parameters())?;
+                reject(method.return_type_annotation())?;
+                let parameters = method.parameters()?;
+                let body = method.body()?;
+                let value = self.function(
+                    "FunctionExpression",
+                    TextRange::new(parameters.range().start(), body.range().end()),
+                    None,
+                    method.async_token().is_some(),
+                    method.star_token().is_some(),
+                    &parameters,
+                    &body,
+                )?;
+
+                self.method_definition(
+                    range,
+                    "method",
+                    self.is_static(method.modifiers().iter())?,
+                    &method.name()?,
+                    value,
+                )?
+            }
+            AnyJsClassMember::JsPropertyClassMember(property) => {
+                reject(property.property_annotation())?;
+                let mut is_static = false;
+                let mut is_accessor = false;
+                for modifier in property.modifiers().iter() {
+                    match modifier.syntax().kind() {
+                        JsSyntaxKind::JS_STATIC_MODIFIER => is_static = true,
+                        JsSyntaxKind::JS_ACCESSOR_MODIFIER => is_accessor = true,
+                        _ => return Err(unsupported(&modifier)),
+                    }
+                }
+                let name = property.name()?;
+                let value = match property.value() {
+                    Some(initializer) => self.expression(&initializer.expression()?)?,
+                    None => Value::Null,
+                };
+
+                self.node(
+                    if is_accessor {
+                        "AccessorProperty"
+                    } else {
+                        "PropertyDefinition"
+                    },
+                    range,
+                    json!({
+                        "static": is_static,
+                        "computed": matches!(name, AnyJsClassMemberName::JsComputedMemberName(_)),
+                        "key": self.class_member_name(&name)?,
+                        "value": value,
+                    }),
+                )
+            }
+            AnyJsClassMember::JsSetterClassMember(setter) => {
+                let body = setter.body()?;
+                let value = self.node(
+                    "FunctionExpression",
+                    TextRange::new(
+                        setter.l_paren_token()?.text_trimmed_range().start(),
+                        body.range().end(),
+                    ),
+                    json!({
+                        "id": null,
+                        "expression": false,
+                        "generator": false,
+                        "async": false,
+                        "params": [self.formal_parameter(&setter.parameter()?)?],
+                        "body": self.function_body(&body)?,
+                    }),
+                );
+
+                self.method_definition(
+                    range,
+                    "set",
+                    self.is_static(setter.modifiers().iter())?,
+                    &setter.name()?,
+                    value,
+                )?
+            }
+            AnyJsClassMember::JsStaticInitializationBlockClassMember(block) => self.node(
+                "StaticBlock",
+                range,
+                json!({ "body": self.statements(&block.statements())? }),
+            ),
+            _ => return Err(unsupported(member)),
+        };
+
+        Ok(Some(member))
+    }
+
+    fn method_definition(
+        &self,
+        range: TextRange,
+        kind: &str,
+        is_static: bool,
+        name: &AnyJsClassMemberName,
+        value: Value,
+    ) -> ConvertResult {
+        Ok(self.node(
+            "MethodDefinition",
+            range,
+            json!({
+                "kind": kind,
+                "static": is_static,
+                "computed": matches!(name, AnyJsClassMemberName::JsComputedMemberName(_)),
+                "key": self.class_member_name(name)?,
+                "value": value,
+            }),
+        ))
+    }
+
+    /// Returns whether the modifiers of a method contain `static`, and fails for any
+    /// other modifier.
+    fn is_static<N: AstNode<Language = JsLanguage>>(
+        &self,
+        modifiers: impl Iterator<Item = N>,
+    ) -> Result<bool, EstreeError> {
+        let mut is_static = false;
+        for modifier in modifiers {
+            if modifier.syntax().kind() != JsSyntaxKind::JS_STATIC_MODIFIER {
+                return Err(unsupported(&modifier));
+            }
+            is_static = true;
+        }
+
+        Ok(is_static)
+    }
+
+    fn class_member_name(&self, name: &AnyJsClassMemberName) -> ConvertResult {
+        match name {
+            AnyJsClassMemberName::JsComputedMemberName(name) => {
+                self.expression(&name.expression()?)
+            }
+            AnyJsClassMemberName::JsLiteralMemberName(name) => {
+                self.literal_member_name(&name.value()?)
+            }
+            AnyJsClassMemberName::JsPrivateClassMemberName(name) => Ok(self.node(
+                "PrivateIdentifier",
+                name.range(),
+                json!({ "name": identifier_name(&name.id_token()?) }),
+            )),
+        }
+    }
+
+    fn object_member_name(&self, name: &AnyJsObjectMemberName) -> ConvertResult {
+        match name {
+            AnyJsObjectMemberName::JsComputedMemberName(name) => {
+                self.expression(&name.expression()?)
+            }
+            AnyJsObjectMemberName::JsLiteralMemberName(name) => {
+                self.literal_member_name(&name.value()?)
+            }
+        }
+    }
+
+    fn literal_member_name(&self, token: &JsSyntaxToken) -> ConvertResult {
+        match token.kind() {
+            JsSyntaxKind::JS_STRING_LITERAL => Ok(self.string_literal(token)),
+            JsSyntaxKind::JS_NUMBER_LITERAL => Ok(self.number_literal(token)),
+            JsSyntaxKind::JS_BIGINT_LITERAL => Ok(self.bigint_literal(token)),
+            _ => Ok(self.identifier(token)),
+        }
+    }
+
+    fn optional_expression(&self, expression: Option<AnyJsExpression>) -> ConvertResult {
+        match expression {
+            Some(expression) => self.expression(&expression),
+            None => Ok(Value::Null),
+        }
+    }
+
+    fn expression(&self, expression: &AnyJsExpression) -> ConvertResult {
+        let range = expression.range();
+
+        let expression = match expression {
+            AnyJsExpression::AnyJsLiteralExpression(literal) => match literal {
+                AnyJsLiteralExpression::JsBigintLiteralExpression(literal) => {
+                    self.bigint_literal(&literal.value_token()?)
+                }
+                AnyJsLiteralExpression::JsBooleanLiteralExpression(literal) => {
+                    let token = literal.value_token()?;
+                    self.node(
+                        "Literal",
+                        range,
+                        json!({ "value": token.kind() == T![true], "raw": token.text_trimmed() }),
+                    )
+                }
+                AnyJsLiteralExpression::JsNullLiteralExpression(_) => {
+                    self.node("Literal", range, json!({ "value": null, "raw": "null" }))
+                }
+                AnyJsLiteralExpression::JsNumberLiteralExpression(literal) => {
+                    self.number_literal(&literal.value_token()?)
+                }
+                AnyJsLiteralExpression::JsRegexLiteralExpression(literal) => {
+                    let token = literal.value_token()?;
+                    let raw = token.text_trimmed();
+                    let end = raw.rfind('/').unwrap_or(raw.len());
+                    self.node(
+                        "Literal",
+                        range,
+                        json!({
+                            "value": null,
+                            "raw": raw,
+                            "regex": {
+                                "pattern": raw.get(1..end).unwrap_or_default(),
+                                "flags": raw.get(end + 1..).unwrap_or_default(),
+                            },
+                        }),
+                    )
+                }
+                AnyJsLiteralExpression::JsStringLiteralExpression(literal) => {
+                    self.string_literal(&literal.value_token()?)
+                }
+            },
+            AnyJsExpression::JsArrayExpression(array) => {
+                let elements = array
+                    .elements()
+                    .iter()
+                    .map(|element| match element? {
+                        AnyJsArrayElement::AnyJsExpression(expression) => {
+                            self.expression(&expression)
+                        }
+                        AnyJsArrayElement::JsArrayHole(_) => Ok(Value::Null),
+                        AnyJsArrayElement::JsSpread(spread) => self.spread(&spread),
+                    })
+                    .collect::<Result<Vec<_>, EstreeError>>()?;
+
+                self.node("ArrayExpression", range, json!({ "elements": elements }))
+            }
+            AnyJsExpression::JsArrowFunctionExpression(arrow) => {
+                reject(arrow.type_parameters())?;
+                reject(arrow.return_type_annotation())?;
+                let params = match arrow.parameters()? {
+                    AnyJsArrowFunctionParameters::AnyJsBinding(binding) => {
+                        json!([self.binding(&binding)?])
+                    }
+                    AnyJsArrowFunctionParameters::JsParameters(parameters) => {
+                        self.parameters(&parameters)?
+                    }
+                };
+                let (is_expression, body) = match arrow.body()? {
+                    AnyJsFunctionBody::AnyJsExpression(body) => (true, self.expression(&body)?),
+                    AnyJsFunctionBody::JsFunctionBody(body) => (false, self.function_body(&body)?),
+                };
+
+                self.node(
+                    "ArrowFunctionExpression",
+                    range,
+                    json!({
+                        "id": null,
+                        "expression": is_expression,
+                        "generator": false,
+                        "async": arrow.async_token().is_some(),
+                        "params": params,
+                        "body": body,
+                    }),
+                )
+            }
+            AnyJsExpression::JsAssignmentExpression(assignment) => self.node(
+                "AssignmentExpression",
+                range,
+                json!({
+                    "operator": assignment.operator_token()?.text_trimmed(),
+                    "left": self.assignment_pattern(&assignment.left()?)?,
+                    "right": self.expression(&assignment.right()?)?,
+                }),
+            ),
+            AnyJsExpression::JsAwaitExpression(expression) => self.node(
+                "AwaitExpression",
+                range,
+                json!({ "argument": self.expression(&expression.argument()?)? }),
+            ),
+            AnyJsExpression::JsBinaryExpression(binary) => self.node(
+                "BinaryExpression",
+                range,
+                json!({
+                    "operator": binary.operator_token()?.text_trimmed(),
+                    "left": self.expression(&binary.left()?)?,
+                    "right": self.expression(&binary.right()?)?,
+                }),
+            ),
+            AnyJsExpression::JsCallExpression(_)
+            | AnyJsExpression::JsComputedMemberExpression(_)
+            | AnyJsExpression::JsStaticMemberExpression(_) => {
+                let (element, is_chain) = self.chain_element(expression)?;
+                if is_chain {
+                    self.node("ChainExpression", range, json!({ "expression": element }))
+                } else {
+                    element
+                }
+            }
+            AnyJsExpression::JsClassExpression(class) => {
+                reject_decorators(&class.decorators())?;
+                reject(class.type_parameters())?;
+                reject(class.implements_clause())?;
+                self.class(
+                    "ClassExpression",
+                    range,
+                    class.id(),
+                    class.extends_clause(),
+                    &class.members(),
+                    class.l_curly_token()?,
+                    class.r_curly_token()?,
+                )?
+            }
+            AnyJsExpression::JsConditionalExpression(conditional) => self.node(
+                "ConditionalExpression",
+                range,
+                json!({
+                    "test": self.expression(&conditional.test()?)?,
+                    "consequent": self.expression(&conditional.consequent()?)?,
+                    "alternate": self.expression(&conditional.alternate()?)?,
+                }),
+            ),
+            AnyJsExpression::JsFunctionExpression(function) => {
+                reject(function.type_parameters())?;
+                reject(function.return_type_annotation())?;
+                self.function(
+                    "FunctionExpression",
+                    range,
+                    function.id(),
+                    function.async_token().is_some(),
+                    function.star_token().is_some(),
+                    &function.parameters()?,
+                    &function.body()?,
+                )?
+            }
+            AnyJsExpression::JsIdentifierExpression(identifier) => {
+                self.reference(&identifier.name()?)?
+            }
+            AnyJsExpression::JsImportCallExpression(import) => {
+                let mut arguments = self.arguments(&import.arguments()?)?.into_iter();
+                self.node(
+                    "ImportExpression",
+                    range,
+                    json!({
+                        "source": arguments.next().unwrap_or(Value::Null),
+                        "options": arguments.next().unwrap_or(Value::Null),
+                    }),
+                )
+            }
+            AnyJsExpression::JsImportMetaExpression(meta) => self.node(
+                "MetaProperty",
+                range,
+                json!({
+                    "meta": self.identifier(&meta.import_token()?),
+                    "property": self.identifier(&meta.meta_token()?),
+                }),
+            ),
+            AnyJsExpression::JsInExpression(expression) => {
+                let left = match expression.property()? {
+                    AnyJsInProperty::AnyJsExpression(property) => self.expression(&property)?,
+                    AnyJsInProperty::JsPrivateName(name) => self.node(
+                        "PrivateIdentifier",
+                        name.range(),
+                        json!({ "name": identifier_name(&name.value_token()?) }),
+                    ),
+                };
+
+                self.node(
+                    "BinaryExpression",
+                    range,
+                    json!({
+                        "operator": "in",
+                        "left": left,
+                        "right": self.expression(&expression.object()?)?,
+                    }),
+                )
+            }
+            AnyJsExpression::JsInstanceofExpression(expression) => self.node(
+                "BinaryExpression",
+                range,
+                json!({
+                    "operator": "instanceof",
+                    "left": self.expression(&expression.left()?)?,
+                    "right": self.expression(&expression.right()?)?,
+                }),
+            ),
+            AnyJsExpression::JsLogicalExpression(logical) => self.node(
+                "LogicalExpression",
+                range,
+                json!({
+                    "operator": logical.operator_token()?.text_trimmed(),
+                    "left": self.expression(&logical.left()?)?,
+                    "right": self.expression(&logical.right()?)?,
+                }),
+            ),
+            AnyJsExpression::JsNewExpression(new) => {
+                reject(new.type_arguments())?;
+                let arguments = match new.arguments() {
+                    Some(arguments) => self.arguments(&arguments)?,
+                    None => Vec::new(),
+                };
+
+                self.node(
+                    "NewExpression",
+                    range,
+                    json!({
+                        "callee": self.expression(&new.callee()?)?,
+                        "arguments": arguments,
+                    }),
+                )
+            }
+            AnyJsExpression::JsNewTargetExpression(target) => self.node(
+                "MetaProperty",
+                range,
+                json!({
+                    "meta": self.identifier(&target.new_token()?),
+                    "property": self.identifier(&target.target_token()?),
+                }),
+            ),
+            AnyJsExpression::JsObjectExpression(object) => {
+                let properties = object
+                    .members()
+                    .iter()
+                    .map(|member| self.object_member(&member?))
+                    .collect::<Result<Vec<_>, EstreeError>>()?;
+
+                self.node(
+                    "ObjectExpression",
+                    range,
+                    json!({ "properties": properties }),
+                )
+            }
+            AnyJsExpression::JsParenthesizedExpression(expression) => {
+                return self.expression(&expression.expression()?)
+            }
+            AnyJsExpression::JsPostUpdateExpression(update) => self.node(
+                "UpdateExpression",
+                range,
+                json!({
+                    "operator": update.operator_token()?.text_trimmed(),
+                    "prefix": false,
+                    "argument": self.assignment(&update.operand()?)?,
+                }),
+            ),
+            AnyJsExpression::JsPreUpdateExpression(update) => self.node(
+                "UpdateExpression",
+                range,
+                json!({
+                    "operator": update.operator_token()?.text_trimmed(),
+                    "prefix": true,
+                    "argument": self.assignment(&update.operand()?)?,
+                }),
+            ),
+            AnyJsExpression::JsSequenceExpression(sequence) => {
+                // The tree nests sequences to the left, ESTree lists all expressions of a sequence
+                let mut expressions = vec![self.expression(&sequence.right()?)?];
+                let mut left = sequence.left()?;
+                while let AnyJsExpression::JsSequenceExpression(sequence) = left {
+                    expressions.push(self.expression(&sequence.right()?)?);
+                    left = sequence.left()?;
+                }
+                expressions.push(self.expression(&left)?);
+                expressions.reverse();
+
+                self.node(
+                    "SequenceExpression",
+                    range,
+                    json!({ "expressions": expressions }),
+                )
+            }
+            AnyJsExpression::JsSuperExpression(_) => self.node("Super", range, json!({})),
+            AnyJsExpression::JsTemplateExpression(template) => {
+                reject(template.type_arguments())?;
+                let quasi = self.template_literal(template)?;
+
+                match template.tag() {
+                    Some(tag) => self.node(
+                        "TaggedTemplateExpression",
+                        range,
+                        json!({ "tag": self.expression(&tag)?, "quasi": quasi }),
+                    ),
+                    None => quasi,
+                }
+            }
+            AnyJsExpression::JsThisExpression(_) => self.node("ThisExpression", range, json!({})),
+            AnyJsExpression::JsUnaryExpression(unary) => self.node(
+                "UnaryExpression",
+                range,
+                json!({
+                    "operator": unary.operator_token()?.text_trimmed(),
+                    "prefix": true,
+                    "argument": self.expression(&unary.argument()?)?,
+                }),
+            ),
+            AnyJsExpression::JsYieldExpression(expression) => {
+                let (delegate, argument) = match expression.argument() {
+                    Some(argument) => (
+                        argument.star_token().is_some(),
+                        self.expression(&argument.expression()?)?,
+                    ),
+                    None => (false, Value::Null),
+                };
+
+                self.node(
+                    "YieldExpression",
+                    range,
+                    json!({ "delegate": delegate, "argument": argument }),
+                )
+            }
+            _ => return Err(unsupported(expression)),
+        };
+
+        Ok(expression)
+    }
+
+    /// Converts a member or call expression without wrapping it in a `ChainExpression`,
+    /// and returns whether it's part of an optional chain.
+    fn chain_element(&self, expression: &AnyJsExpression) -> Result<(Value, bool), EstreeError> {
+        let range = expression.range();
+        let object = |object: AnyJsExpression| match object {
+            AnyJsExpression::JsCallExpression(_)
+            | AnyJsExpression::JsComputedMemberExpression(_)
+            | AnyJsExpression::JsStaticMemberExpression(_) => self.chain_element(&object),
+            object => Ok((self.expression(&object)?, false)),
+        };
+
+        match expression {
+            AnyJsExpression::JsCallExpression(call) => {
+                reject(call.type_arguments())?;
+                let (callee, is_chain) = object(call.callee()?)?;
+                let optional = call.optional_chain_token().is_some();
+
+                Ok((
+                    self.node(
+                        "CallExpression",
+                        range,
+                        json!({
+                            "callee": callee,
+                            "arguments": self.arguments(&call.arguments()?)?,
+                            "optional": optional,
+                        }),
+                    ),
+                    is_chain || optional,
+                ))
+            }
+            AnyJsExpression::JsComputedMemberExpression(member) => {
+                let (object, is_chain) = object(member.object()?)?;
+                let optional = member.optional_chain_token().is_some();
+
+                Ok((
+                    self.node(
+                        "MemberExpression",
+                        range,
+                        json!({
+                            "object": object,
+                            "property": self.expression(&member.member()?)?,
+                            "computed": true,
+                            "optional": optional,
+                        }),
+                    ),
+                    is_chain || optional,
+                ))
+            }
+            AnyJsExpression::JsStaticMemberExpression(member) => {
+                let (object, is_chain) = object(member.object()?)?;
+                let optional = member.operator_token()?.kind() == T![?.];
+
+                Ok((
+                    self.node(
+                        "MemberExpression",
+                        range,
+                        json!({
+                            "object": object,
+                            "property": self.name(&member.member()?)?,
+                            "computed": false,
+                            "optional": optional,
+                        }),
+                    ),
+                    is_chain || optional,
+                ))
+            }
+            expression => Ok((self.expression(expression)?, false)),
+        }
+    }
+
+    fn arguments(&self, arguments: &JsCallArguments) -> Result<Vec<Value>, EstreeError> {
+        arguments
+            .args()
+            .iter()
+            .map(|argument| match argument? {
+                AnyJsCallArgument::AnyJsExpression(expression) => self.expression(&expression),
+                AnyJsCallArgument::JsSpread(spread) => self.spread(&spread),
+            })
+            .collect()
+    }
+
+    fn spread(&self, spread: &JsSpread) -> ConvertResult {
+        Ok(self.node(
+            "SpreadElement",
+            spread.range(),
+            json!({ "argument": self.expression(&spread.argument()?)? }),
+        ))
+    }
+
+    fn object_member(&self, member: &AnyJsObjectMember) -> ConvertResult {
+        let range = member.range();
+        let property = |kind: &str,
+                        key: &AnyJsObjectMemberName,
+                        value: Value,
+                        method: bool|
+         -> ConvertResult {
+            Ok(self.node(
+                "Property",
+                range,
+                json!({
+                    "kind": kind,
+                    "method": method,
+                    "shorthand": false,
+                    "computed": matches!(key, AnyJsObjectMemberName::JsComputedMemberName(_)),
+                    "key": self.object_member_name(key)?,
+                    "value": value,
+                }),
+            ))
+        };
+
+        match member {
+            AnyJsObjectMember::JsGetterObjectMember(getter) => {
+                reject(getter.return_type())?;
+                let body = getter.body()?;
+                let value = self.node(
+                    "FunctionExpression",
+                    TextRange::new(
+                        getter.l_paren_token()?.text_trimmed_range().start(),
+                        body.range().end(),
+                    ),
+                    json!({
+                        "id": null,
+                        "expression": false,
+                        "generator": false,
+                        "async": false,
+                        "params": [],
+                        "body": self.function_body(&body)?,
+                    }),
+                );
+                property("get", &getter.name()?, value, false)
+            }
+            AnyJsObjectMember::JsMethodObjectMember(method) => {
+                reject(method.type_parameters())?;
+                reject(method.return_type_annotation())?;
+                let parameters = method.parameters()?;
+                let body = method.body()?;
+                let value = self.function(
+                    "FunctionExpression",
+                    TextRange::new(parameters.range().start(), body.range().end()),
+                    None,
+                    method.async_token().is_some(),
+                    method.star_token().is_some(),
+                    &parameters,
+                    &body,
+                )?;
+                property("init", &method.name()?, value, true)
+            }
+            AnyJsObjectMember::JsPropertyObjectMember(member) => {
+                let value = self.expression(&member.value()?)?;
+                property("init", &member.name()?, value, false)
+            }
+            AnyJsObjectMember::JsSetterObjectMember(setter) => {
+                let body = setter.body()?;
+                let value = self.node(
+                    "FunctionExpression",
+                    TextRange::new(
+                        setter.l_paren_token()?.text_trimmed_range().start(),
+                        body.range().end(),
+                    ),
+                    json!({
+                        "id": null,
+                        "expression": false,
+                        "generator": false,
+                        "async": false,
+                        "params": [self.formal_parameter(&setter.parameter()?)?],
+                        "body": self.function_body(&body)?,
+                    }),
+                );
+                property("set", &setter.name()?, value, false)
+            }
+            AnyJsObjectMember::JsShorthandPropertyObjectMember(member) => {
+                let name = self.reference(&member.name()?)?;
+                Ok(self.shorthand_property(range, name.clone(), name))
+            }
+            AnyJsObjectMember::JsSpread(spread) => self.spread(spread),
+            AnyJsObjectMember::JsBogusMember(_) => Err(unsupported(member)),
+        }
+    }
+
+    fn shorthand_property(&self, range: TextRange, key: Value, value: Value) -> Value {
+        self.node(
+            "Property",
+            range,
+            json!({
+                "kind": "init",
+                "method": false,
+                "shorthand": true,
+                "computed": false,
+                "key": key,
+                "value": value,
+            }),
+        )
+    }
+
+    fn template_literal(&self, template: &JsTemplateExpression) -> ConvertResult {
+        let mut quasis = Vec::new();
+        let mut expressions = Vec::new();
+        // The chunks since the last substitution, or the empty range after it if there are none
+        let mut chunk = TextRange::empty(template.l_tick_token()?.text_trimmed_range().end());
+        let mut has_chunk = false;
+
+        for element in template.elements().iter() {
+            match element {
+                AnyJsTemplateElement::JsTemplateChunkElement(element) => {
+                    let token_range = element.template_chunk_token()?.text_trimmed_range();
+                    chunk = if has_chunk {
+                        chunk.cover(token_range)
+                    } else {
+                        token_range
+                    };
+                    has_chunk = true;
+                }
+                AnyJsTemplateElement::JsTemplateElement(element) => {
+                    quasis.push(self.template_element(chunk, false));
+                    expressions.push(self.expression(&element.expression()?)?);
+                    chunk = TextRange::empty(element.r_curly_token()?.text_trimmed_range().end());
+                    has_chunk = false;
+                }
+            }
+        }
+        quasis.push(self.template_element(chunk, true));
+
+        let range = TextRange::new(
+            template.l_tick_token()?.text_trimmed_range().start(),
+            template.r_tick_token()?.text_trimmed_range().end(),
+        );
+
+        Ok(self.node(
+            "TemplateLiteral",
+            range,
+            json!({ "quasis": quasis, "expressions": expressions }),
+        ))
+    }
+
+    fn template_element(&self, range: TextRange, tail: bool) -> Value {
+        let start = usize::from(range.start() - self.locations.base);
+        let end = usize::from(range.end() - self.locations.base);
+        // Line terminators in templates are normalized to line feeds
+        let raw = self.locations.text[start..end]
+            .replace("\r\n", "\n")
+            .replace('\r', "\n");
+        // Tagged templates may contain invalid escapes, which have no cooked value
+        let cooked = cook(&raw, true);
+
+        self.node(
+            "TemplateElement",
+            range,
+            json!({ "value": { "raw": raw, "cooked": cooked }, "tail": tail }),
+        )
+    }
+
+    fn binding(&self, binding: &AnyJsBinding) -> ConvertResult {
+        match binding {
+            AnyJsBinding::JsIdentifierBinding(binding) => {
+                Ok(self.identifier(&binding.name_token()?))
+            }
+            AnyJsBinding::JsBogusBinding(_) => Err(unsupported(binding)),
+        }
+    }
+
+    fn reference(&self, reference: &JsReferenceIdentifier) -> ConvertResult {
+        Ok(self.identifier(&reference.value_token()?))
+    }
+
+    fn name(&self, name: &AnyJsName) -> ConvertResult {
+        match name {
+            AnyJsName::JsName(name) => Ok(self.identifier(&name.value_token()?)),
+            AnyJsName::JsPrivateName(name) => Ok(self.node(
+                "PrivateIdentifier",
+                name.range(),
+                json!({ "name": identifier_name(&name.value_token()?) }),
+            )),
+        }
+    }
+
+    fn binding_pattern(&self, pattern: &AnyJsBindingPattern) -> ConvertResult {
+        let range = pattern.range();
+
+        match pattern {
+            AnyJsBindingPattern::AnyJsBinding(binding) => self.binding(binding),
+            AnyJsBindingPattern::JsArrayBindingPattern(pattern) => {
+                let elements = pattern
+                    .elements()
+                    .iter()
+                    .map(|element| match element? {
+                        AnyJsArrayBindingPatternElement::JsArrayBindingPatternElement(element) => {
+                            let pattern = self.binding_pattern(&element.pattern()?)?;
+                            self.with_default(element.range(), pattern, element.init())
+                        }
+                        AnyJsArrayBindingPatternElement::JsArrayBindingPatternRestElement(rest) => {
+                            Ok(self.node(
+                                "RestElement",
+                                rest.range(),
+                                json!({ "argument": self.binding_pattern(&rest.pattern()?)? }),
+                            ))
+                        }
+                        AnyJsArrayBindingPatternElement::JsArrayHole(_) => Ok(Value::Null),
+                    })
+                    .collect::<Result<Vec<_>, EstreeError>>()?;
+
+                Ok(self.node("ArrayPattern", range, json!({ "elements": elements })))
+            }
+            AnyJsBindingPattern::JsObjectBindingPattern(pattern) => {
+                let properties = pattern
+                    .properties()
+                    .iter()
+                    .map(|property| match property? {
+                        AnyJsObjectBindingPatternMember::JsObjectBindingPatternProperty(
+                            property,
+                        ) => {
+                            let pattern = property.pattern()?;
+                            let value = self.binding_pattern(&pattern)?;
+                            let value_range = TextRange::new(
+                                pattern.range().start(),
+                                property.range().end(),
+                            );
+                            let value = self.with_default(value_range, value, property.init())?;
+                            let key = property.member()?;
+
+                            Ok(self.node(
+                                "Property",
+                                property.range(),
+                                json!({
+                                    "kind": "init",
+                                    "method": false,
+                                    "shorthand": false,
+                                    "computed": matches!(key, AnyJsObjectMemberName::JsComputedMemberName(_)),
+                                    "key": self.object_member_name(&key)?,
+                                    "value": value,
+                                }),
+                            ))
+                        }
+                        AnyJsObjectBindingPatternMember::JsObjectBindingPatternShorthandProperty(
+                            property,
+                        ) => {
+                            let key = self.binding(&property.identifier()?)?;
+                            let value =
+                                self.with_default(property.range(), key.clone(), property.init())?;
+                            Ok(self.shorthand_property(property.range(), key, value))
+                        }
+                        AnyJsObjectBindingPatternMember::JsObjectBindingPatternRest(rest) => {
+                            Ok(self.node(
+                                "RestElement",
+                                rest.range(),
+                                json!({ "argument": self.binding(&rest.binding()?)? }),
+                            ))
+                        }
+                        property @ AnyJsObjectBindingPatternMember::JsBogusBinding(_) => {
+                            Err(unsupported(&property))
+                        }
+                    })
+                    .collect::<Result<Vec<_>, EstreeError>>()?;
+
+                Ok(self.node("ObjectPattern", range, json!({ "properties": properties })))
+            }
+        }
+    }
+
+    fn assignment(&self, assignment: &AnyJsAssignment) -> ConvertResult {
+        let range = assignment.range();
+
+        match assignment {
+            AnyJsAssignment::JsComputedMemberAssignment(member) => Ok(self.node(
+                "MemberExpression",
+                range,
+                json!({
+                    "object": self.expression(&member.object()?)?,
+                    "property": self.expression(&member.member()?)?,
+                    "computed": true,
+                    "optional": false,
+                }),
+            )),
+            AnyJsAssignment::JsIdentifierAssignment(identifier) => {
+                Ok(self.identifier(&identifier.name_token()?))
+            }
+            AnyJsAssignment::JsParenthesizedAssignment(assignment) => {
+                self.assignment(&assignment.assignment()?)
+            }
+            AnyJsAssignment::JsStaticMemberAssignment(member) => Ok(self.node(
+                "MemberExpression",
+                range,
+                json!({
+                    "object": self.expression(&member.object()?)?,
+                    "property": self.name(&member.member()?)?,
+                    "computed": false,
+                    "optional": false,
+                }),
+            )),
+            _ => Err(unsupported(assignment)),
+        }
+    }
+
+    fn assignment_pattern(&self, pattern: &AnyJsAssignmentPattern) -> ConvertResult {
+        let range = pattern.range();
+
+        match pattern {
+            AnyJsAssignmentPattern::AnyJsAssignment(assignment) => self.assignment(assignment),
+            AnyJsAssignmentPattern::JsArrayAssignmentPattern(pattern) => {
+                let elements = pattern
+                    .elements()
+                    .iter()
+                    .map(|element| match element? {
+                        AnyJsArrayAssignmentPatternElement::JsArrayAssignmentPatternElement(
+                            element,
+                        ) => {
+                            let pattern = self.assignment_pattern(&element.pattern()?)?;
+                            self.with_default(element.range(), pattern, element.init())
+                        }
+                        AnyJsArrayAssignmentPatternElement::JsArrayAssignmentPatternRestElement(
+                            rest,
+                        ) => Ok(self.node(
+                            "RestElement",
+                            rest.range(),
+                            json!({ "argument": self.assignment_pattern(&rest.pattern()?)? }),
+                        )),
+                        AnyJsArrayAssignmentPatternElement::JsArrayHole(_) => Ok(Value::Null),
+                    })
+                    .collect::<Result<Vec<_>, EstreeError>>()?;
+
+                Ok(self.node("ArrayPattern", range, json!({ "elements": elements })))
+            }
+            AnyJsAssignmentPattern::JsObjectAssignmentPattern(pattern) => {
+                let properties = pattern
+                    .properties()
+                    .iter()
+                    .map(|property| match property? {
+                        AnyJsObjectAssignmentPatternMember::JsObjectAssignmentPatternProperty(
+                            property,
+                        ) => {
+                            let pattern = property.pattern()?;
+                            let value = self.assignment_pattern(&pattern)?;
+                            let value_range = TextRange::new(
+                                pattern.range().start(),
+                                property.range().end(),
+                            );
+                            let value = self.with_default(value_range, value, property.init())?;
+                            let key = property.member()?;
+
+                            Ok(self.node(
+                                "Property",
+                                property.range(),
+                                json!({
+                                    "kind": "init",
+                                    "method": false,
+                                    "shorthand": false,
+                                    "computed": matches!(key, AnyJsObjectMemberName::JsComputedMemberName(_)),
+                                    "key": self.object_member_name(&key)?,
+                                    "value": value,
+                                }),
+                            ))
+                        }
+                        AnyJsObjectAssignmentPatternMember::JsObjectAssignmentPatternShorthandProperty(
+                            property,
+                        ) => {
+                            let key = self.identifier(&property.identifier()?.name_token()?);
+                            let value =
+                                self.with_default(property.range(), key.clone(), property.init())?;
+                            Ok(self.shorthand_property(property.range(), key, value))
+                        }
+                        AnyJsObjectAssignmentPatternMember::JsObjectAssignmentPatternRest(rest) => {
+                            Ok(self.node(
+                                "RestElement",
+                                rest.range(),
+                                json!({ "argument": self.assignment(&rest.target()?)? }),
+                            ))
+                        }
+                        property @ AnyJsObjectAssignmentPatternMember::JsBogusAssignment(_) => {
+                            Err(unsupported(&property))
+                        }
+                    })
+                    .collect::<Result<Vec<_>, EstreeError>>()?;
+
+                Ok(self.node("ObjectPattern", range, json!({ "properties": properties })))
+            }
+        }
+    }
+
+    /// Wraps `pattern` in an `AssignmentPattern` spanning `range` if it has a default value.
+    fn with_default(
+        &self,
+        range: TextRange,
+        pattern: Value,
+        init: Option<JsInitializerClause>,
+    ) -> ConvertResult {
+        match init {
+            Some(init) => Ok(self.node(
+                "AssignmentPattern",
+                range,
+                json!({ "left": pattern, "right": self.expression(&init.expression()?)? }),
+            )),
+            None => Ok(pattern),
+        }
+    }
+
+    fn module_source(&self, source: &JsModuleSource) -> ConvertResult {
+        Ok(self.string_literal(&source.value_token()?))
+    }
+
+    /// Converts an imported or exported name, which may be a string since ES2022.
+    fn export_name(&self, name: &JsLiteralExportName) -> ConvertResult {
+        let token = name.value()?;
+        if token.kind() == JsSyntaxKind::JS_STRING_LITERAL {
+            Ok(self.string_literal(&token))
+        } else {
+            Ok(self.identifier(&token))
+        }
+    }
+
+    fn identifier(&self, token: &JsSyntaxToken) -> Value {
+        self.node(
+            "Identifier",
+            token.text_trimmed_range(),
+            json!({ "name": identifier_name(token) }),
+        )
+    }
+
+    fn string_literal(&self, token: &JsSyntaxToken) -> Value {
+        let raw = token.text_trimmed();
+        let value = raw
+            .get(1..raw.len().saturating_sub(1))
+            .and_then(|inner| cook(inner, false));
+
+        self.node(
+            "Literal",
+            token.text_trimmed_range(),
+            json!({ "value": value, "raw": raw }),
+        )
+    }
+
+    fn number_literal(&self, token: &JsSyntaxToken) -> Value {
+        let raw = token.text_trimmed();
+        let value = match parse_js_number(raw) {
+            // Integers are written without a fraction, like `JSON.stringify` does
+            Some(value) if value.fract() == 0.0 && value.abs() < 9_007_199_254_740_992.0 => {
+                Value::from(value as i64)
+            }
+            Some(value) => Value::from(value),
+            None => Value::Null,
+        };
+
+        self.node(
+            "Literal",
+            token.text_trimmed_range(),
+            json!({ "value": value, "raw": raw }),
+        )
+    }
+
+    fn bigint_literal(&self, token: &JsSyntaxToken) -> Value {
+        let raw = token.text_trimmed();
+        let bigint = raw.trim_end_matches('n').replace('_', "");
+
+        // BigInts can't be represented in JSON, so their value is `null` like for regexes
+        self.node(
+            "Literal",
+            token.text_trimmed_range(),
+            json!({ "value": null, "raw": raw, "bigint": bigint }),
+        )
+    }
+}
+
+fn variable_kind(await_token: Option<JsSyntaxToken>, kind: &JsSyntaxToken) -> String {
+    match await_token {
+        Some(_) => format!("await {}", kind.text_trimmed()),
+        None => kind.text_trimmed().to_string(),
+    }
+}
+
+fn identifier_name(token: &JsSyntaxToken) -> String {
+    let text = token.text_trimmed();
+    if text.contains('\\') {
+        cook(text, false).unwrap_or_else(|| text.to_string())
+    } else {
+        text.to_string()
+    }
+}
+
+/// Returns the value of the string literal or template chunk `raw` with all escape sequences
+/// resolved, or `None` if it contains an invalid escape.
+///
+/// Lone surrogates can't be represented in Rust strings and are replaced by U+FFFD.
+fn cook(raw: &str, is_template: bool) -> Option<String> {
+    fn push_char(units: &mut Vec<u16>, c: char) {
+        let mut buffer = [0; 2];
+        units.extend_from_slice(c.encode_utf16(&mut buffer));
+    }
+
+    fn hex(chars: &mut Peekable<Chars<'_>>, digits: usize) -> Option<u32> {
+        let mut value = 0u32;
+        for _ in 0..digits {
+            value = value * 16 + chars.next()?.to_digit(16)?;
+        }
+        Some(value)
+    }
+
+    let mut units: Vec<u16> = Vec::with_capacity(raw.len());
+    let mut chars = raw.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            push_char(&mut units, c);
+            continue;
+        }
+
+        match chars.next()? {
+            'n' => units.push(u16::from(b'\n')),
+            't' => units.push(u16::from(b'\t')),
+            'r' => units.push(u16::from(b'\r')),
+            'b' => units.push(0x08),
+            'f' => units.push(0x0c),
+            'v' => units.push(0x0b),
+            // Line continuations
+            '\r' => {
+                if chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+            }
+            '\n' | '\u{2028}' | '\u{2029}' => {}
+            'x' => units.push(hex(&mut chars, 2)? as u16),
+            'u' => {
+                let value = if chars.peek() == Some(&'{') {
+                    chars.next();
+                    let mut value = 0u32;
+                    loop {
+                        match chars.next()? {
+                            '}' => break,
+                            digit => value = value.checked_mul(16)? + digit.to_digit(16)?,
+                        }
+                        if value > 0x10_FFFF {
+                            return None;
+                        }
+                    }
+                    value
+                } else {
+                    hex(&mut chars, 4)?
+                };
+
+                match char::from_u32(value) {
+                    Some(c) => push_char(&mut units, c),
+                    // A surrogate, which may be half of a pair written as two escapes
+                    None => units.push(value as u16),
+                }
+            }
+            '0' if !chars.peek().is_some_and(char::is_ascii_digit) => units.push(0),
+            '0'..='9' if is_template => return None,
+            digit @ '0'..='7' => {
+                // Legacy octal escapes have up to three digits and a value up to 255
+                let mut value = digit.to_digit(8)?;
+                let max_digits = if digit <= '3' { 2 } else { 1 };
+                for _ in 0..max_digits {
+                    match chars.peek().and_then(|c| c.to_digit(8)) {
+                        Some(digit) => {
+                            value = value * 8 + digit;
+                            chars.next();
+                        }
+                        None => break,
+                    }
+                }
+                units.push(value as u16);
+            }
+            c => push_char(&mut units, c),
+        }
+    }
+
+    Some(String::from_utf16_lossy(&units))
+}
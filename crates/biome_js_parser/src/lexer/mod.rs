@@ -19,6 +19,7 @@
 mod errors;
 mod tests;
 
+use biome_diagnostics::Severity;
 use biome_js_syntax::JsSyntaxKind::*;
 pub use biome_js_syntax::*;
 use biome_parser::diagnostic::ParseDiagnostic;
@@ -31,6 +32,7 @@ use biome_unicode_table::{
     Dispatch::{self, *},
 };
 use bitflags::bitflags;
+use std::ops::Range;
 
 use self::errors::invalid_digits_after_unicode_escape_sequence;
 
@@ -107,7 +109,7 @@ pub enum JsReLexContext {
 
 /// An extremely fast, lookup table based, lossless ECMAScript lexer
 #[derive(Debug)]
-pub(crate) struct JsLexer<'src> {
+pub struct JsLexer<'src> {
     /// Source text
     source: &'src str,
 
@@ -131,6 +133,20 @@ pub(crate) struct JsLexer<'src> {
     current_flags: TokenFlags,
 
     diagnostics: Vec<ParseDiagnostic>,
+
+    /// `true` if identifiers containing bidirectional control characters or mixing
+    /// confusable scripts should be reported.
+    check_confusable_identifiers: bool,
+
+    /// `true` if unicode escapes in string literals and template chunks that produce a lone
+    /// surrogate should be reported.
+    check_lone_surrogates: bool,
+
+    /// `true` if the lexer lexed or re-lexed any token in a JSX context.
+    lexed_jsx: bool,
+
+    /// `true` if the lexer re-lexed any token in a TypeScript only context.
+    lexed_typescript: bool,
 }
 
 impl<'src> Lexer<'src> for JsLexer<'src> {
@@ -168,6 +184,13 @@ impl<'src> Lexer<'src> for JsLexer<'src> {
         self.current_start = TextSize::from(self.position as u32);
         self.current_flags = TokenFlags::empty();
 
+        if matches!(
+            context,
+            JsLexContext::JsxChild | JsLexContext::JsxAttributeValue
+        ) {
+            self.lexed_jsx = true;
+        }
+
         let kind = if self.is_eof() {
             EOF
         } else {
@@ -183,6 +206,10 @@ impl<'src> Lexer<'src> for JsLexer<'src> {
             .set(TokenFlags::PRECEDING_LINE_BREAK, self.after_newline);
         self.current_kind = kind;
 
+        if self.check_lone_surrogates && matches!(kind, JS_STRING_LITERAL | TEMPLATE_CHUNK) {
+            self.report_lone_surrogates();
+        }
+
         if !kind.is_trivia() {
             self.after_newline = false;
         }
@@ -263,6 +290,12 @@ impl<'src> ReLexer<'src> for JsLexer<'src> {
         let old_position = self.position;
         self.position = u32::from(self.current_start) as usize;
 
+        match context {
+            JsReLexContext::JsxIdentifier | JsReLexContext::JsxChild => self.lexed_jsx = true,
+            JsReLexContext::TypeArgumentLessThan => self.lexed_typescript = true,
+            JsReLexContext::Regex | JsReLexContext::BinaryOperator => {}
+        }
+
         let re_lexed_kind = match context {
             JsReLexContext::Regex if matches!(self.current(), T![/] | T![/=]) => self.read_regex(),
             JsReLexContext::BinaryOperator => self.re_lex_binary_operator(),
@@ -299,6 +332,7 @@ impl<'src> LexerWithCheckpoint<'src> for JsLexer<'src> {
 
 impl<'src> JsLexer<'src> {
     /// Make a new lexer from a str, this is safe because strs are valid utf8
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(source: &'src str) -> Self {
         Self {
             source,
@@ -309,9 +343,37 @@ impl<'src> JsLexer<'src> {
             current_flags: TokenFlags::empty(),
             position: 0,
             diagnostics: vec![],
+            check_confusable_identifiers: false,
+            check_lone_surrogates: false,
+            lexed_jsx: false,
+            lexed_typescript: false,
         }
     }
 
+    /// Returns `true` if the lexer lexed or re-lexed any token as JSX.
+    pub(crate) fn has_lexed_jsx(&self) -> bool {
+        self.lexed_jsx
+    }
+
+    /// Returns `true` if the lexer re-lexed any token as TypeScript syntax.
+    pub(crate) fn has_lexed_typescript(&self) -> bool {
+        self.lexed_typescript
+    }
+
+    /// Enables the reporting of identifiers containing bidirectional control characters
+    /// or mixing confusable scripts.
+    pub(crate) fn with_confusable_identifiers_check(mut self, enabled: bool) -> Self {
+        self.check_confusable_identifiers = enabled;
+        self
+    }
+
+    /// Enables the reporting of unicode escapes in string literals and template chunks that
+    /// produce a surrogate that isn't part of a surrogate pair.
+    pub(crate) fn with_lone_surrogates_check(mut self, enabled: bool) -> Self {
+        self.check_lone_surrogates = enabled;
+        self
+    }
+
     fn re_lex_binary_operator(&mut self) -> JsSyntaxKind {
         if self.current_byte() == Some(b'>') {
             match self.next_byte() {
@@ -847,7 +909,11 @@ impl<'src> JsLexer<'src> {
             // FIXME: This should use ID_Continue, not XID_Continue
             UNI => {
                 let chr = self.current_char_unchecked();
-                let res = is_js_id_continue(chr);
+                // Bidi control characters aren't valid identifier parts. We still
+                // consume them when confusables are checked so that the identifier
+                // stays intact and the diagnostic covers the whole token.
+                let res = is_js_id_continue(chr)
+                    || (self.check_confusable_identifiers && is_bidi_control(chr));
                 if res {
                     self.advance(chr.len_utf8() - 1);
                     Some((chr, false))
@@ -936,6 +1002,10 @@ impl<'src> JsLexer<'src> {
             self.current_flags |= TokenFlags::UNICODE_ESCAPE;
         }
 
+        if self.check_confusable_identifiers {
+            self.check_confusable_identifier();
+        }
+
         match &buf[..count + len] {
             // Keywords
             b"break" => BREAK_KW,
@@ -1026,6 +1096,83 @@ impl<'src> JsLexer<'src> {
         }
     }
 
+    /// Reports the identifier spanning from the start of the current token to the current
+    /// position if it contains bidirectional control characters or mixes confusable scripts.
+    fn check_confusable_identifier(&mut self) {
+        let start = u32::from(self.current_start) as usize;
+        let text = &self.source[start..self.position];
+
+        if text.is_ascii() {
+            return;
+        }
+
+        if text.chars().any(is_bidi_control) {
+            let err = ParseDiagnostic::new(
+                "identifier contains a bidirectional control character",
+                start..self.position,
+            )
+            .with_hint("bidirectional control characters can make the code appear differently than it is interpreted");
+            self.push_diagnostic(err);
+        } else if is_mixed_script(text) {
+            let err = ParseDiagnostic::new(
+                "identifier mixes characters from different scripts",
+                start..self.position,
+            )
+            .with_hint("characters from different scripts can look identical, making this identifier confusable with another one");
+            self.push_diagnostic(err);
+        }
+    }
+
+    /// Reports the `\u` escapes of the current string literal or template chunk that produce a
+    /// high surrogate that isn't followed by a low surrogate, or a low surrogate that doesn't
+    /// follow a high surrogate.
+    fn report_lone_surrogates(&mut self) {
+        let start = u32::from(self.current_start) as usize;
+        let source = self.source;
+        let text = &source.as_bytes()[start..self.position];
+
+        // The range of the last high surrogate escape, waiting for its low surrogate
+        let mut high_surrogate: Option<Range<usize>> = None;
+        let mut lone_surrogates = vec![];
+        let mut index = 0;
+
+        while index < text.len() {
+            let escape = match text[index..] {
+                [b'\\', b'u', ..] => unicode_escape_value(&text[index + 2..]),
+                _ => None,
+            };
+
+            let Some((value, length)) = escape else {
+                lone_surrogates.extend(high_surrogate.take());
+                // Skip the escaped character, so that `\\u` isn't read as an escape
+                index += if text[index] == b'\\' { 2 } else { 1 };
+                continue;
+            };
+
+            let range = start + index..start + index + 2 + length;
+            index += 2 + length;
+
+            match value {
+                0xD800..=0xDBFF => lone_surrogates.extend(high_surrogate.replace(range)),
+                0xDC00..=0xDFFF => {
+                    if high_surrogate.take().is_none() {
+                        lone_surrogates.push(range);
+                    }
+                }
+                _ => lone_surrogates.extend(high_surrogate.take()),
+            }
+        }
+
+        lone_surrogates.extend(high_surrogate);
+
+        for range in lone_surrogates {
+            let err = ParseDiagnostic::new("unicode escape produces a lone surrogate", range)
+                .with_hint("lone surrogates aren't valid UTF-16 and may be rejected or replaced by tools expecting valid unicode")
+                .with_severity(Severity::Warning);
+            self.push_diagnostic(err);
+        }
+    }
+
     #[inline]
     fn special_number_start<F: Fn(char) -> bool>(&mut self, func: F) -> bool {
         if self.byte_at(2).map_or(false, |b| func(b as char)) {
@@ -1998,6 +2145,51 @@ impl<'src> JsLexer<'src> {
     }
 }
 
+/// Returns `true` if `chr` is a Unicode bidirectional formatting character
+fn is_bidi_control(chr: char) -> bool {
+    matches!(
+        chr,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+/// Returns the value of the unicode escape whose digits, `XXXX` or `{X...}`, start `text`, and
+/// the length of the digits. Returns `None` for invalid escapes, which the lexer already reported.
+fn unicode_escape_value(text: &[u8]) -> Option<(u32, usize)> {
+    let (digits, length) = match text.first()? {
+        b'{' => {
+            let end = text.iter().position(|byte| *byte == b'}')?;
+            (&text[1..end], end + 1)
+        }
+        _ => (text.get(..4)?, 4),
+    };
+
+    if digits.is_empty() || !digits.iter().all(u8::is_ascii_hexdigit) {
+        return None;
+    }
+
+    let digits = std::str::from_utf8(digits).ok()?;
+    let value = u32::from_str_radix(digits, 16).ok()?;
+    Some((value, length))
+}
+
+/// Returns `true` if the identifier mixes letters of the Latin, Greek, and Cyrillic scripts,
+/// which contain many characters that are visually indistinguishable from each other.
+fn is_mixed_script(ident: &str) -> bool {
+    let mut scripts = 0u8;
+
+    for chr in ident.chars() {
+        scripts |= match chr {
+            'a'..='z' | 'A'..='Z' | '\u{00C0}'..='\u{024F}' => 0b001,
+            '\u{0370}'..='\u{03FF}' => 0b010,
+            '\u{0400}'..='\u{04FF}' => 0b100,
+            _ => 0,
+        };
+    }
+
+    scripts.count_ones() > 1
+}
+
 /// Check if a char is a JS linebreak
 fn is_linebreak(chr: char) -> bool {
     matches!(chr, '\n' | '\r' | '\u{2028}' | '\u{2029}')
@@ -11,6 +11,8 @@
 //!
 //! You probably do not want to use the parser struct, unless you want to parse fragments of Js source code or make your own productions.
 //! Instead use functions such as [parse_script], and [parse_module] which offer abstracted versions for parsing.
+//! Use [parse_expression] to parse a standalone expression and [parse_type] to parse a standalone
+//! TypeScript type.
 //!
 //! For more finer control, use [parse](crate::parse::parse()) or [parse_js_with_cache],
 //!
@@ -27,7 +29,8 @@
 //! - Cheap incremental reparsing of changed text.
 //!
 //! The crate further includes utilities such as:
-//! - ANSI syntax highlighting of nodes or text through `lexer`.
+//! - ANSI syntax highlighting of nodes or text through [util].
+//! - Conversion of syntax trees to ESTree shaped JSON through [estree].
 //!
 //! It is inspired by the rust analyzer parser but adapted for JavaScript.
 //!
@@ -123,24 +126,28 @@ pub mod test_utils;
 #[cfg(test)]
 mod tests;
 
+pub mod estree;
 pub mod options;
 mod prelude;
 pub mod syntax;
+mod token_sink;
 mod token_source;
+pub mod util;
 
 use crate::prelude::*;
 pub(crate) use crate::ParsedSyntax::{Absent, Present};
 pub use crate::{
-    lexer::{JsLexContext, JsReLexContext},
+    lexer::{JsLexContext, JsLexer, JsReLexContext},
     options::JsParserOptions,
     parse::*,
 };
 use biome_js_factory::JsSyntaxFactory;
-use biome_js_syntax::{JsLanguage, JsSyntaxKind, LanguageVariant};
-use biome_parser::tree_sink::LosslessTreeSink;
+use biome_js_syntax::{JsLanguage, JsSyntaxKind, LanguageVariant, LanguageVersion};
+pub use biome_parser::tree_sink::{LosslessTreeSink, LossySourceMap, LossyTreeSink};
 pub(crate) use parser::{JsParser, ParseRecoveryTokenSet};
 pub(crate) use state::{JsParserState, StrictMode};
 use std::fmt::Debug;
+pub use token_sink::TokenSinkAdapter;
 
 pub enum JsSyntaxFeature {
     #[allow(unused)]
@@ -149,6 +156,14 @@ pub enum JsSyntaxFeature {
     StrictMode,
     TypeScript,
     Jsx,
+    /// Syntax introduced in the given ECMAScript version
+    MinimumVersion(LanguageVersion),
+    /// Decorators, which are supported in TypeScript and in [LanguageVersion::ESNext]
+    Decorators,
+    /// Flow-only type syntax, such as maybe types and `%checks` predicates
+    Flow,
+    /// Type annotations and type parameters, which are supported in TypeScript and Flow
+    TypeAnnotations,
 }
 
 impl SyntaxFeature for JsSyntaxFeature {
@@ -158,8 +173,17 @@ impl SyntaxFeature for JsSyntaxFeature {
         match self {
             JsSyntaxFeature::SloppyMode => p.state().strict().is_none(),
             JsSyntaxFeature::StrictMode => p.state().strict().is_some(),
-            JsSyntaxFeature::TypeScript => p.source_type().language().is_typescript(),
+            JsSyntaxFeature::TypeScript => p.source_type().is_typescript(),
             JsSyntaxFeature::Jsx => p.source_type().variant() == LanguageVariant::Jsx,
+            JsSyntaxFeature::MinimumVersion(version) => p.source_type().version() >= *version,
+            JsSyntaxFeature::Decorators => {
+                JsSyntaxFeature::TypeScript.is_supported(p)
+                    || p.source_type().version() >= LanguageVersion::ESNext
+            }
+            JsSyntaxFeature::Flow => p.source_type().language().is_flow(),
+            JsSyntaxFeature::TypeAnnotations => {
+                JsSyntaxFeature::TypeScript.is_supported(p) || JsSyntaxFeature::Flow.is_supported(p)
+            }
         }
     }
 }
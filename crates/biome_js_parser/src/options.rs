@@ -6,14 +6,94 @@ pub struct JsParserOptions {
     ///
     /// This parameter decorators belong to the old language proposal.
     pub parse_class_parameter_decorators: bool,
+
+    /// Whether identifiers containing bidirectional control characters (e.g. a right-to-left override)
+    /// or mixing characters of confusable scripts (e.g. Latin and Cyrillic) should be reported.
+    ///
+    /// The identifiers are still parsed as identifiers.
+    #[serde(default)]
+    pub check_confusable_identifiers: bool,
+
+    /// Whether integer literals larger than `Number.MAX_SAFE_INTEGER`, which lose precision
+    /// when evaluated, should be reported.
+    ///
+    /// The literals are still parsed as number literals.
+    #[serde(default)]
+    pub check_unsafe_integers: bool,
+
+    /// Whether unicode escapes in string literals and template literals that produce a lone
+    /// surrogate, like `"\uD800"`, should be reported as warnings.
+    ///
+    /// Lone surrogates are valid in JavaScript strings, but not in UTF-16 text.
+    #[serde(default)]
+    pub check_lone_surrogates: bool,
+
+    /// The maximum number of diagnostics to retain. Further diagnostics are replaced by a single
+    /// diagnostic telling how many were omitted. `None` retains all diagnostics.
+    ///
+    /// The limit doesn't affect error recovery, the syntax tree is the same with or without it.
+    #[serde(default)]
+    pub max_diagnostics: Option<usize>,
+
+    /// The maximum nesting depth of statements and expressions. Deeper syntax isn't parsed, the
+    /// parser skips its tokens into a bogus node and reports a diagnostic instead of recursing
+    /// until the stack overflows. `None` uses [JsParserOptions::DEFAULT_MAX_NESTING_DEPTH].
+    ///
+    /// Lower the limit when parsing on threads with a small stack.
+    #[serde(default)]
+    pub max_nesting_depth: Option<usize>,
+
+    /// Whether `return` statements are allowed outside of functions, as in CommonJS modules
+    /// that Node.js wraps in a function.
+    #[serde(default)]
+    pub allow_return_outside_function: bool,
 }
 
 impl JsParserOptions {
+    /// The default of [JsParserOptions::max_nesting_depth], far deeper than any handwritten code.
+    pub const DEFAULT_MAX_NESTING_DEPTH: usize = 1024;
+
     pub fn with_parse_class_parameter_decorators(mut self) -> Self {
         self.parse_class_parameter_decorators = true;
         self
     }
 
+    pub fn with_check_confusable_identifiers(mut self) -> Self {
+        self.check_confusable_identifiers = true;
+        self
+    }
+
+    pub fn with_check_unsafe_integers(mut self) -> Self {
+        self.check_unsafe_integers = true;
+        self
+    }
+
+    pub fn with_check_lone_surrogates(mut self) -> Self {
+        self.check_lone_surrogates = true;
+        self
+    }
+
+    pub fn with_max_diagnostics(mut self, max: usize) -> Self {
+        self.max_diagnostics = Some(max);
+        self
+    }
+
+    pub fn with_max_nesting_depth(mut self, max: usize) -> Self {
+        self.max_nesting_depth = Some(max);
+        self
+    }
+
+    pub fn with_allow_return_outside_function(mut self) -> Self {
+        self.allow_return_outside_function = true;
+        self
+    }
+
+    /// Returns the maximum nesting depth of statements and expressions.
+    pub fn nesting_depth_limit(&self) -> usize {
+        self.max_nesting_depth
+            .unwrap_or(Self::DEFAULT_MAX_NESTING_DEPTH)
+    }
+
     /// Should parse parameter decorators inside classes, e.g.:
     ///
     /// ```js
@@ -1,19 +1,45 @@
 //! Utilities for high level parsing of js code.
 
+use crate::lexer::JsLexer;
 use crate::*;
+use biome_console::fmt::{Formatter, Termcolor};
+use biome_console::markup;
+use biome_diagnostics::{termcolor, DiagnosticExt, PrintDiagnostic};
+use biome_js_syntax::numbers::{parse_js_number, split_into_radix_and_number};
 use biome_js_syntax::{
-    AnyJsRoot, JsFileSource, JsLanguage, JsModule, JsScript, JsSyntaxNode, ModuleKind,
+    AnyJsClass, AnyJsClassMember, AnyJsClassMemberName, AnyJsConstructorParameter,
+    AnyJsDeclarationClause, AnyJsExportClause, AnyJsExportDefaultDeclaration, AnyJsExpression,
+    AnyJsLiteralExpression, AnyJsModuleItem, AnyJsObjectMemberName, AnyJsOptionalChainExpression,
+    AnyJsParameter, AnyJsPropertyModifier, AnyJsRoot, AnyJsStatement, JsArrayElementList,
+    JsBigintLiteralExpression, JsBinaryExpression, JsBinaryOperator, JsCallArgumentList,
+    JsComputedMemberAssignment, JsComputedMemberExpression, JsConstructorParameterList,
+    JsDirective, JsExpressionSnipped, JsFileSource, JsImport, JsLanguage, JsModule,
+    JsNamedImportSpecifierList, JsNumberLiteralExpression, JsObjectMemberList, JsParameterList,
+    JsPropertyObjectMember, JsScript, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken,
+    JsVariableDeclaration, ModuleKind, TextRange, TextSize, TsAccessibilityModifier, TsTypeSnipped,
+    T,
 };
 use biome_parser::event::Event;
+use biome_parser::lexer::{BufferedLexer, Lexer};
 use biome_parser::token_source::Trivia;
-use biome_rowan::{AstNode, NodeCache};
+use biome_parser::tree_sink::TreeSink;
+use biome_rowan::{
+    AstNode, AstNodeList, AstSeparatedList, Direction, FileSourceError, NodeCache, SendNode,
+    SyntaxKind, TriviaPiece, WalkEvent,
+};
 use std::marker::PhantomData;
+use std::panic::AssertUnwindSafe;
+use std::path::{Path, PathBuf};
 
 /// A utility struct for managing the result of a parser job
 #[derive(Debug)]
 pub struct Parse<T> {
     root: JsSyntaxNode,
     errors: Vec<ParseDiagnostic>,
+    source_type: Option<JsFileSource>,
+    events: Option<ParseEvents>,
+    max_depth_reached: bool,
+    has_top_level_await: bool,
     _ty: PhantomData<T>,
 }
 
@@ -30,13 +56,49 @@ impl<T> Parse<T> {
         Parse {
             root,
             errors,
+            source_type: None,
+            events: None,
+            max_depth_reached: false,
+            has_top_level_await: false,
             _ty: PhantomData,
         }
     }
 
+    /// Records the source type of the parsed source, resolving [ModuleKind::Unknown] to the
+    /// module kind the parser chose.
+    pub(crate) fn with_source_type(mut self, source_type: JsFileSource) -> Self {
+        let source_type = if source_type.module_kind() == ModuleKind::Unknown {
+            source_type.with_module_kind(self.module_kind())
+        } else {
+            source_type
+        };
+        self.source_type = Some(source_type);
+        self
+    }
+
+    /// Records whether the parser skipped syntax that exceeded the maximum nesting depth.
+    pub(crate) fn with_max_depth_reached(mut self, max_depth_reached: bool) -> Self {
+        self.max_depth_reached = max_depth_reached;
+        self
+    }
+
+    /// Records whether the parser parsed an `await` outside of any function.
+    pub(crate) fn with_top_level_await(mut self, has_top_level_await: bool) -> Self {
+        self.has_top_level_await = has_top_level_await;
+        self
+    }
+
     pub fn cast<N: AstNode<Language = JsLanguage>>(self) -> Option<Parse<N>> {
         if N::can_cast(self.syntax().kind()) {
-            Some(Parse::new(self.root, self.errors))
+            Some(Parse {
+                root: self.root,
+                errors: self.errors,
+                source_type: self.source_type,
+                events: self.events,
+                max_depth_reached: self.max_depth_reached,
+                has_top_level_await: self.has_top_level_await,
+                _ty: PhantomData,
+            })
         } else {
             None
         }
@@ -77,10 +139,1457 @@ impl<T> Parse<T> {
         self.errors
     }
 
+    /// Returns whether the source was parsed as a script or as a module.
+    ///
+    /// The module kind is taken from the source type if the parse stores one, otherwise it's
+    /// derived from the root node.
+    pub fn module_kind(&self) -> ModuleKind {
+        match self
+            .source_type
+            .map(|source_type| source_type.module_kind())
+        {
+            Some(ModuleKind::Script) => ModuleKind::Script,
+            Some(ModuleKind::Module) => ModuleKind::Module,
+            Some(ModuleKind::Unknown) | None => {
+                if self.root.kind() == JsSyntaxKind::JS_SCRIPT {
+                    ModuleKind::Script
+                } else {
+                    ModuleKind::Module
+                }
+            }
+        }
+    }
+
+    /// Returns the result of parsing the source with the text at `edit` replaced by
+    /// `replacement`.
+    ///
+    /// Edits inside of a string literal that can't change how the rest of the source parses
+    /// reuse the existing tree and only replace the string's token. Edits within a single
+    /// top-level statement that ends with a `;` or a block only reparse that statement and
+    /// reuse the nodes of all other statements. Any other edit, or an edit of a source with
+    /// diagnostics, falls back to parsing the whole edited source.
+    ///
+    /// `edit` is relative to the start of the source and must be within it. `options` must be
+    /// the options that the source was parsed with: the parse doesn't store its options, and the
+    /// nodes that are reused were produced with the original options.
+    ///
+    /// ```
+    /// use biome_js_parser::{JsParserOptions, parse_module};
+    /// use biome_rowan::{TextRange, TextSize};
+    ///
+    /// let parse = parse_module("let a = 'hello';", JsParserOptions::default());
+    /// let edit = TextRange::new(TextSize::from(9), TextSize::from(14));
+    /// let reparsed = parse.reparse(edit, "world", JsParserOptions::default());
+    /// assert_eq!(reparsed.syntax().text(), "let a = 'world';");
+    /// ```
+    pub fn reparse(
+        &self,
+        edit: TextRange,
+        replacement: &str,
+        options: JsParserOptions,
+    ) -> Parse<T> {
+        if let Some(parse) = self.reparse_string_literal(edit, replacement) {
+            return parse;
+        }
+
+        let source_type = self
+            .source_type
+            .unwrap_or_else(|| match self.module_kind() {
+                ModuleKind::Script => JsFileSource::js_script(),
+                ModuleKind::Module | ModuleKind::Unknown => JsFileSource::js_module(),
+            });
+
+        if let Some(parse) = self.reparse_statement(edit, replacement, source_type, &options) {
+            return parse;
+        }
+
+        let mut text = self.root.text().to_string();
+        text.replace_range(std::ops::Range::<usize>::from(edit), replacement);
+        let (root, errors, max_depth_reached, has_top_level_await) = match self.root.kind() {
+            JsSyntaxKind::JS_EXPRESSION_SNIPPED => {
+                let parse = parse_expression(&text, source_type, options);
+                (
+                    parse.root,
+                    parse.errors,
+                    parse.max_depth_reached,
+                    parse.has_top_level_await,
+                )
+            }
+            JsSyntaxKind::TS_TYPE_SNIPPED => {
+                let parse = parse_type(&text, source_type, options);
+                (
+                    parse.root,
+                    parse.errors,
+                    parse.max_depth_reached,
+                    parse.has_top_level_await,
+                )
+            }
+            _ => {
+                let parse = parse(&text, source_type, options);
+                (
+                    parse.root,
+                    parse.errors,
+                    parse.max_depth_reached,
+                    parse.has_top_level_await,
+                )
+            }
+        };
+
+        Parse::new(root, errors)
+            .with_source_type(source_type)
+            .with_max_depth_reached(max_depth_reached)
+            .with_top_level_await(has_top_level_await)
+    }
+
+    /// Replaces the string literal token that contains `edit`, if the edit can't change the
+    /// structure of the tree or its diagnostics.
+    fn reparse_string_literal(&self, edit: TextRange, replacement: &str) -> Option<Parse<T>> {
+        // Quotes and line breaks end the string, and escapes may be invalid.
+        if !self.errors.is_empty()
+            || replacement.contains(['\'', '"', '\\', '\n', '\r', '\u{2028}', '\u{2029}'])
+        {
+            return None;
+        }
+
+        let token = self.root.covering_element(edit).into_token()?;
+        let range = token.text_trimmed_range();
+        // The edit must not touch the quotes
+        if token.kind() != JsSyntaxKind::JS_STRING_LITERAL
+            || edit.start() <= range.start()
+            || edit.end() >= range.end()
+        {
+            return None;
+        }
+
+        // Directives change the strict mode, and literal member names can be duplicated `__proto__`s
+        if matches!(
+            token.parent()?.kind(),
+            JsSyntaxKind::JS_DIRECTIVE | JsSyntaxKind::JS_LITERAL_MEMBER_NAME
+        ) {
+            return None;
+        }
+
+        let mut text = token.text().to_string();
+        text.replace_range(
+            std::ops::Range::<usize>::from(edit - token.text_range().start()),
+            replacement,
+        );
+        let leading = token
+            .leading_trivia()
+            .pieces()
+            .map(|piece| TriviaPiece::new(piece.kind(), piece.text_len()))
+            .collect::<Vec<_>>();
+        let trailing = token
+            .trailing_trivia()
+            .pieces()
+            .map(|piece| TriviaPiece::new(piece.kind(), piece.text_len()))
+            .collect::<Vec<_>>();
+        let new_token = JsSyntaxToken::new_detached(token.kind(), &text, leading, trailing);
+        let root = self
+            .root
+            .clone()
+            .replace_child(token.into(), new_token.into())?;
+
+        Some(Parse {
+            root,
+            errors: Vec::new(),
+            source_type: self.source_type,
+            events: None,
+            max_depth_reached: self.max_depth_reached,
+            has_top_level_await: self.has_top_level_await,
+            _ty: PhantomData,
+        })
+    }
+
+    /// Reparses the top-level statement that contains `edit` on its own and replaces it in
+    /// the existing tree, if the edit can't change how the neighbouring statements parse.
+    fn reparse_statement(
+        &self,
+        edit: TextRange,
+        replacement: &str,
+        source_type: JsFileSource,
+        options: &JsParserOptions,
+    ) -> Option<Parse<T>> {
+        // A sloppy statement parses differently once it's no longer preceded by a `use strict` directive
+        if !self.errors.is_empty() || (self.module_kind() == ModuleKind::Script && self.is_strict())
+        {
+            return None;
+        }
+
+        let statement = top_level_statements(&self.root)
+            .find(|statement| statement.text_range().contains_range(edit))?;
+
+        let mut text = statement.text().to_string();
+        text.replace_range(
+            std::ops::Range::<usize>::from(edit - statement.text_range().start()),
+            replacement,
+        );
+
+        let parse = parse(
+            &text,
+            source_type.with_module_kind(self.module_kind()),
+            options.clone(),
+        );
+        if !parse.errors.is_empty() {
+            return None;
+        }
+
+        let mut statements = top_level_statements(&parse.root);
+        let reparsed = statements.next()?;
+        // The text must form exactly one statement, without directives or trailing trivia
+        // that the parser attached to the end of the file
+        if statements.next().is_some() || reparsed.text_range().len() != TextSize::of(text.as_str())
+        {
+            return None;
+        }
+
+        // The previous statement ends at the same token as long as the first token is unchanged
+        let first_token = statement.first_token()?;
+        let reparsed_first_token = reparsed.first_token()?;
+        if first_token.kind() != reparsed_first_token.kind()
+            || first_token.text() != reparsed_first_token.text()
+        {
+            return None;
+        }
+
+        // The next statement only parses the same if this one can't continue into it
+        let last_token = reparsed.last_token()?;
+        let is_terminated = last_token.kind() == T![;]
+            || (last_token.kind() == T!['}']
+                && matches!(
+                    reparsed.kind(),
+                    JsSyntaxKind::JS_FUNCTION_DECLARATION
+                        | JsSyntaxKind::JS_CLASS_DECLARATION
+                        | JsSyntaxKind::JS_BLOCK_STATEMENT
+                ));
+        if !is_terminated {
+            return None;
+        }
+
+        // Without walking the other statements, the tree can't tell whether the replaced
+        // statement was the only one with a top-level await.
+        if self.has_top_level_await && !parse.has_top_level_await {
+            return None;
+        }
+
+        let root = self
+            .root
+            .clone()
+            .replace_child(statement.into(), reparsed.detach().into())?;
+
+        Some(Parse {
+            root,
+            errors: Vec::new(),
+            source_type: self.source_type,
+            events: None,
+            max_depth_reached: self.max_depth_reached || parse.max_depth_reached,
+            has_top_level_await: self.has_top_level_await || parse.has_top_level_await,
+            _ty: PhantomData,
+        })
+    }
+
+    /// Returns the events and trivia the tree was built from, if they were retained by
+    /// [parse_retaining_events].
+    ///
+    /// They can be fed to [process](biome_parser::event::process) with another
+    /// [TreeSink] without lexing and parsing the source again.
+    pub fn into_events(self) -> Option<ParseEvents> {
+        self.events
+    }
+
+    /// Returns the file source, including the language version, the tree was parsed with.
+    ///
+    /// Returns `None` if this [Parse] was created with [Parse::new] instead of by one of the
+    /// parse functions of this crate.
+    pub fn source_type(&self) -> Option<JsFileSource> {
+        self.source_type
+    }
+
+    /// Returns `true` if the parser skipped statements or expressions that were nested deeper
+    /// than [JsParserOptions::max_nesting_depth]. The skipped tokens are wrapped in bogus nodes.
+    ///
+    /// ```
+    /// use biome_js_parser::{JsParserOptions, parse_module};
+    ///
+    /// let options = JsParserOptions::default().with_max_nesting_depth(16);
+    /// assert!(parse_module("[[[[[[[[[[[[1]]]]]]]]]]]]", options.clone()).max_depth_reached());
+    /// assert!(!parse_module("[[1]]", options).max_depth_reached());
+    /// ```
+    pub fn max_depth_reached(&self) -> bool {
+        self.max_depth_reached
+    }
+
+    /// Returns the depth of the deepest node of the tree. The root has a depth of 1.
+    pub fn tree_depth(&self) -> usize {
+        let mut depth = 0;
+        let mut max_depth = 0;
+
+        for event in self.root.preorder() {
+            match event {
+                WalkEvent::Enter(_) => {
+                    depth += 1;
+                    max_depth = max_depth.max(depth);
+                }
+                WalkEvent::Leave(_) => depth -= 1,
+            }
+        }
+
+        max_depth
+    }
+
+    /// Returns `true` if the top-level code runs in strict mode.
+    ///
+    /// Modules are always strict. Scripts are strict if their directive prologue contains
+    /// a `"use strict"` directive.
+    ///
+    /// ```
+    /// use biome_js_parser::{JsParserOptions, parse_module, parse_script};
+    ///
+    /// assert!(parse_module("a = 1;", JsParserOptions::default()).is_strict());
+    /// assert!(parse_script("'use strict'; a = 1;", JsParserOptions::default()).is_strict());
+    /// assert!(!parse_script("a = 1; 'use strict';", JsParserOptions::default()).is_strict());
+    /// ```
+    pub fn is_strict(&self) -> bool {
+        match JsScript::cast_ref(&self.root) {
+            Some(script) => script.directives().iter().any(|directive| {
+                directive
+                    .inner_string_text()
+                    .is_ok_and(|text| text == "use strict")
+            }),
+            None => self.module_kind() == ModuleKind::Module,
+        }
+    }
+
+    /// Returns the hashbang of the program, such as `#!/usr/bin/env node`, if it has one.
+    ///
+    /// ```
+    /// use biome_js_parser::{JsParserOptions, parse_module};
+    ///
+    /// let parse = parse_module("#!/usr/bin/env node\nrun();", JsParserOptions::default());
+    /// assert_eq!(parse.hashbang().unwrap().text_trimmed(), "#!/usr/bin/env node");
+    /// assert!(parse_module("run();", JsParserOptions::default()).hashbang().is_none());
+    /// ```
+    pub fn hashbang(&self) -> Option<JsSyntaxToken> {
+        match AnyJsRoot::cast(self.syntax())? {
+            AnyJsRoot::JsModule(module) => module.interpreter_token(),
+            AnyJsRoot::JsScript(script) => script.interpreter_token(),
+            _ => None,
+        }
+    }
+
+    /// Returns the directives of the program's prologue, such as `"use strict"`, in source order.
+    ///
+    /// String literal statements that follow the first non-directive statement aren't directives.
+    ///
+    /// ```
+    /// use biome_js_parser::{JsParserOptions, parse_script};
+    ///
+    /// let parse = parse_script("'use strict'; 'use asm'; run(); 'late';", JsParserOptions::default());
+    /// let directives: Vec<_> = parse
+    ///     .directives()
+    ///     .iter()
+    ///     .map(|directive| directive.inner_string_text().unwrap().to_string())
+    ///     .collect();
+    /// assert_eq!(directives, ["use strict", "use asm"]);
+    /// ```
+    pub fn directives(&self) -> Vec<JsDirective> {
+        match AnyJsRoot::cast(self.syntax()) {
+            Some(AnyJsRoot::JsModule(module)) => module.directives().iter().collect(),
+            Some(AnyJsRoot::JsScript(script)) => script.directives().iter().collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns `true` if the program awaits outside of any function: an `await` expression,
+    /// a `for await` loop, or an `await using` declaration.
+    ///
+    /// The parser records this while parsing, so awaits inside function bodies, parameters,
+    /// class field initializers, and static blocks don't count, but awaits in computed keys
+    /// and `extends` clauses of classes do.
+    ///
+    /// ```
+    /// use biome_js_parser::{JsParserOptions, parse_module};
+    ///
+    /// let parse = parse_module("const data = await load();", JsParserOptions::default());
+    /// assert!(parse.has_top_level_await());
+    ///
+    /// let parse = parse_module("async function f() { await load(); }", JsParserOptions::default());
+    /// assert!(!parse.has_top_level_await());
+    /// ```
+    pub fn has_top_level_await(&self) -> bool {
+        self.has_top_level_await
+    }
+
     /// Returns [true] if the parser encountered some errors during the parsing.
     pub fn has_errors(&self) -> bool {
         self.errors.iter().any(|diagnostic| diagnostic.is_error())
     }
+
+    /// Returns the diagnostics with an error severity.
+    pub fn errors(&self) -> impl Iterator<Item = &ParseDiagnostic> {
+        self.errors
+            .iter()
+            .filter(|diagnostic| diagnostic.is_error())
+    }
+
+    /// Returns the diagnostics that don't make the tree invalid, such as warnings.
+    pub fn warnings(&self) -> impl Iterator<Item = &ParseDiagnostic> {
+        self.errors
+            .iter()
+            .filter(|diagnostic| !diagnostic.is_error())
+    }
+
+    /// Renders the diagnostics as a plain text report, ready to print to a terminal.
+    ///
+    /// Each diagnostic is printed with a `path:line:column` header, its message, and an
+    /// excerpt of `source` with the diagnostic's range underlined.
+    ///
+    /// ```
+    /// use biome_js_parser::{JsParserOptions, parse_module};
+    ///
+    /// let source = "let a = ;";
+    /// let parse = parse_module(source, JsParserOptions::default());
+    /// let report = parse.render_diagnostics("example.js", source);
+    /// assert!(report.contains("example.js:1:9"));
+    /// ```
+    pub fn render_diagnostics(&self, path: &str, source: &str) -> String {
+        let mut buffer = termcolor::Buffer::no_color();
+
+        for diagnostic in &self.errors {
+            let diagnostic = diagnostic
+                .clone()
+                .with_file_path(path)
+                .with_file_source_code(source);
+            Formatter::new(&mut Termcolor(&mut buffer))
+                .write_markup(markup! {
+                    {PrintDiagnostic::simple(&diagnostic)}
+                })
+                .expect("failed to emit diagnostic");
+        }
+
+        String::from_utf8(buffer.into_inner()).expect("non utf8 in diagnostic buffer")
+    }
+
+    /// Returns `true` if evaluating the program may have observable side effects.
+    ///
+    /// Imports, exports, and declarations are considered free of side effects, as long as
+    /// their initializers are. Classes are free of side effects unless they have decorators,
+    /// static blocks, or an `extends` clause, computed member names, or static field initializers
+    /// that may have side effects. Calls and `new` expressions annotated with `/* @__PURE__ */`
+    /// or `/* #__PURE__ */` are considered free of side effects too.
+    ///
+    /// ```
+    /// use biome_js_parser::{JsParserOptions, parse_module};
+    ///
+    /// let parse = parse_module("export const a = /* @__PURE__ */ create();", JsParserOptions::default());
+    /// assert!(!parse.has_top_level_side_effects());
+    ///
+    /// let parse = parse_module("window.a = 1;", JsParserOptions::default());
+    /// assert!(parse.has_top_level_side_effects());
+    /// ```
+    pub fn has_top_level_side_effects(&self) -> bool {
+        match AnyJsRoot::cast(self.syntax()) {
+            Some(AnyJsRoot::JsModule(module)) => module.items().iter().any(|item| match item {
+                AnyJsModuleItem::AnyJsStatement(statement) => {
+                    statement_has_side_effects(&statement)
+                }
+                AnyJsModuleItem::JsImport(_) => false,
+                AnyJsModuleItem::JsExport(export) => match export.export_clause() {
+                    Ok(AnyJsExportClause::AnyJsDeclarationClause(
+                        AnyJsDeclarationClause::JsVariableDeclarationClause(clause),
+                    )) => clause.declaration().map_or(true, |declaration| {
+                        declaration_has_side_effects(&declaration)
+                    }),
+                    Ok(AnyJsExportClause::AnyJsDeclarationClause(
+                        AnyJsDeclarationClause::JsClassDeclaration(declaration),
+                    )) => class_has_side_effects(&declaration.into()),
+                    Ok(AnyJsExportClause::JsExportDefaultDeclarationClause(clause)) => {
+                        match clause.declaration() {
+                            Ok(AnyJsExportDefaultDeclaration::JsClassExportDefaultDeclaration(
+                                declaration,
+                            )) => class_has_side_effects(&declaration.into()),
+                            Ok(_) => false,
+                            Err(_) => true,
+                        }
+                    }
+                    Ok(AnyJsExportClause::JsExportDefaultExpressionClause(clause)) => clause
+                        .expression()
+                        .map_or(true, |expression| !is_side_effect_free(&expression)),
+                    Ok(_) => false,
+                    Err(_) => true,
+                },
+            }),
+            Some(AnyJsRoot::JsScript(script)) => script
+                .statements()
+                .iter()
+                .any(|statement| statement_has_side_effects(&statement)),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the source has no code, only whitespace and comments.
+    ///
+    /// Directives like `"use strict"`, a hashbang, and empty statements are code.
+    ///
+    /// ```
+    /// use biome_js_parser::{JsParserOptions, parse_module};
+    ///
+    /// let parse = parse_module("// Copyright\n", JsParserOptions::default());
+    /// assert!(parse.is_effectively_empty());
+    ///
+    /// let parse = parse_module("\"use strict\";", JsParserOptions::default());
+    /// assert!(!parse.is_effectively_empty());
+    /// ```
+    pub fn is_effectively_empty(&self) -> bool {
+        match AnyJsRoot::cast(self.syntax()) {
+            Some(AnyJsRoot::JsModule(module)) => {
+                module.interpreter_token().is_none()
+                    && module.directives().is_empty()
+                    && module.items().is_empty()
+            }
+            Some(AnyJsRoot::JsScript(script)) => {
+                script.interpreter_token().is_none()
+                    && script.directives().is_empty()
+                    && script.statements().is_empty()
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the chains of `+` operations that have at least one string literal operand,
+    /// such as `"a" + x + "b"`. These chains can usually be rewritten as template literals.
+    ///
+    /// Chains are returned in source order. A parenthesized chain nested in another chain
+    /// is reported separately.
+    ///
+    /// ```
+    /// use biome_js_parser::{JsParserOptions, parse_module};
+    ///
+    /// let parse = parse_module("let a = 1 + 2; let b = \"a\" + a;", JsParserOptions::default());
+    /// let chains = parse.string_concat_chains();
+    /// assert_eq!(chains.len(), 1);
+    /// assert_eq!(chains[0].operands.len(), 2);
+    /// ```
+    pub fn string_concat_chains(&self) -> Vec<StringConcatChain> {
+        self.syntax()
+            .descendants()
+            .filter_map(JsBinaryExpression::cast)
+            .filter(|expression| {
+                is_plus(expression)
+                    && !expression
+                        .syntax()
+                        .parent()
+                        .and_then(JsBinaryExpression::cast)
+                        .is_some_and(|parent| is_plus(&parent))
+            })
+            .filter_map(|expression| {
+                let mut operands = Vec::new();
+                let mut current = expression.clone();
+                loop {
+                    operands.push(current.right().ok()?);
+                    match current.left().ok()? {
+                        AnyJsExpression::JsBinaryExpression(left) if is_plus(&left) => {
+                            current = left;
+                        }
+                        left => {
+                            operands.push(left);
+                            break;
+                        }
+                    }
+                }
+                operands.reverse();
+
+                let has_string_literal = operands.iter().any(|operand| {
+                    matches!(
+                        operand,
+                        AnyJsExpression::AnyJsLiteralExpression(
+                            AnyJsLiteralExpression::JsStringLiteralExpression(_)
+                        )
+                    )
+                });
+
+                has_string_literal.then(|| StringConcatChain {
+                    range: expression.range(),
+                    operands,
+                })
+            })
+            .collect()
+    }
+
+    /// Reports, for every non-empty comma-separated construct, whether it ends with a
+    /// trailing comma.
+    ///
+    /// The reported constructs are array literals, object literals, parameter lists, call
+    /// arguments, and named imports. Parameter lists ending with a rest parameter are
+    /// skipped because they don't permit a trailing comma.
+    ///
+    /// ```
+    /// use biome_js_parser::{JsParserOptions, parse_module, TrailingCommaContext};
+    ///
+    /// let parse = parse_module("f(a, b,);", JsParserOptions::default());
+    /// let report = parse.trailing_comma_report();
+    /// assert_eq!(report.len(), 1);
+    /// assert_eq!(report[0].context, TrailingCommaContext::Arguments);
+    /// assert!(report[0].has_trailing_comma);
+    /// ```
+    pub fn trailing_comma_report(&self) -> Vec<TrailingComma> {
+        self.syntax()
+            .descendants()
+            .filter_map(|node| match node.kind() {
+                JsSyntaxKind::JS_ARRAY_ELEMENT_LIST => {
+                    let list = JsArrayElementList::unwrap_cast(node);
+                    trailing_comma(&list, TrailingCommaContext::Array)
+                }
+                JsSyntaxKind::JS_OBJECT_MEMBER_LIST => {
+                    let list = JsObjectMemberList::unwrap_cast(node);
+                    trailing_comma(&list, TrailingCommaContext::Object)
+                }
+                JsSyntaxKind::JS_PARAMETER_LIST => {
+                    let list = JsParameterList::unwrap_cast(node);
+                    if matches!(list.last(), Some(Ok(AnyJsParameter::JsRestParameter(_)))) {
+                        return None;
+                    }
+                    trailing_comma(&list, TrailingCommaContext::Parameters)
+                }
+                JsSyntaxKind::JS_CONSTRUCTOR_PARAMETER_LIST => {
+                    let list = JsConstructorParameterList::unwrap_cast(node);
+                    if matches!(
+                        list.last(),
+                        Some(Ok(AnyJsConstructorParameter::JsRestParameter(_)))
+                    ) {
+                        return None;
+                    }
+                    trailing_comma(&list, TrailingCommaContext::Parameters)
+                }
+                JsSyntaxKind::JS_CALL_ARGUMENT_LIST => {
+                    let list = JsCallArgumentList::unwrap_cast(node);
+                    trailing_comma(&list, TrailingCommaContext::Arguments)
+                }
+                JsSyntaxKind::JS_NAMED_IMPORT_SPECIFIER_LIST => {
+                    let list = JsNamedImportSpecifierList::unwrap_cast(node);
+                    trailing_comma(&list, TrailingCommaContext::Imports)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the contiguous run of import statements at the start of a module, and the
+    /// offset at which the rest of the program begins.
+    ///
+    /// Comments between two imports belong to the import section. Comments preceding the
+    /// first statement after the imports belong to the rest of the program. Scripts never
+    /// have an import section.
+    ///
+    /// ```
+    /// use biome_js_parser::{JsParserOptions, parse_module};
+    ///
+    /// let code = "import a from \"a\";\nconsole.log(a);";
+    /// let parse = parse_module(code, JsParserOptions::default());
+    /// let section = parse.import_section();
+    /// assert_eq!(section.imports.len(), 1);
+    /// assert_eq!(&code[usize::from(section.end)..], "\nconsole.log(a);");
+    /// ```
+    pub fn import_section(&self) -> ImportSection {
+        match AnyJsRoot::cast(self.syntax()) {
+            Some(AnyJsRoot::JsModule(module)) => {
+                let items = module.items();
+                let imports: Vec<_> = items
+                    .iter()
+                    .map_while(|item| match item {
+                        AnyJsModuleItem::JsImport(import) => Some(import),
+                        _ => None,
+                    })
+                    .collect();
+                let end = imports.last().map_or_else(
+                    || items.syntax().text_range().start(),
+                    |import| import.syntax().text_range().end(),
+                );
+
+                ImportSection { imports, end }
+            }
+            Some(AnyJsRoot::JsScript(script)) => ImportSection {
+                imports: Vec::new(),
+                end: script.statements().syntax().text_range().start(),
+            },
+            _ => ImportSection {
+                imports: Vec::new(),
+                end: self.syntax().text_range().start(),
+            },
+        }
+    }
+
+    /// Returns the ranges of the object members that set the prototype of their object,
+    /// such as `__proto__: o` in `{ __proto__: o }`.
+    ///
+    /// Only literal `__proto__` names set the prototype: computed names like `["__proto__"]`,
+    /// shorthand properties, and methods define a regular own property.
+    ///
+    /// ```
+    /// use biome_js_parser::{JsParserOptions, parse_module};
+    ///
+    /// let parse = parse_module("let a = { \"__proto__\": o, ['__proto__']: o };", JsParserOptions::default());
+    /// assert_eq!(parse.proto_setters().len(), 1);
+    /// ```
+    pub fn proto_setters(&self) -> Vec<TextRange> {
+        self.syntax()
+            .descendants()
+            .filter_map(JsPropertyObjectMember::cast)
+            .filter(|member| {
+                matches!(
+                    member.name(),
+                    Ok(AnyJsObjectMemberName::JsLiteralMemberName(name))
+                        if name.name().is_ok_and(|name| name == "__proto__")
+                )
+            })
+            .map(|member| member.range())
+            .collect()
+    }
+
+    /// Returns every optional chaining site (`?.`) in source order, and whether it guards
+    /// a member access or a call.
+    ///
+    /// ```
+    /// use biome_js_parser::{JsParserOptions, OptionalChainKind, parse_module};
+    ///
+    /// let parse = parse_module("a?.b; a?.[b]; a?.(); a.b?.();", JsParserOptions::default());
+    /// let kinds: Vec<_> = parse.optional_chains().iter().map(|chain| chain.kind).collect();
+    /// assert_eq!(
+    ///     kinds,
+    ///     [
+    ///         OptionalChainKind::Member,
+    ///         OptionalChainKind::Member,
+    ///         OptionalChainKind::Call,
+    ///         OptionalChainKind::Call
+    ///     ]
+    /// );
+    /// ```
+    pub fn optional_chains(&self) -> Vec<OptionalChain> {
+        self.syntax()
+            .descendants()
+            .filter_map(AnyJsOptionalChainExpression::cast)
+            .filter_map(|expression| {
+                let operator = expression.optional_chain_token()?;
+                let kind = match expression {
+                    AnyJsOptionalChainExpression::JsCallExpression(_) => OptionalChainKind::Call,
+                    AnyJsOptionalChainExpression::JsStaticMemberExpression(_)
+                    | AnyJsOptionalChainExpression::JsComputedMemberExpression(_) => {
+                        OptionalChainKind::Member
+                    }
+                };
+
+                Some(OptionalChain {
+                    kind,
+                    range: expression.range(),
+                    operator_range: operator.text_trimmed_range(),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns every computed member access (`obj[key]`) in source order, including
+    /// assignment targets, with the ranges of its object and key.
+    ///
+    /// A key that is a string literal is reported as static, since `obj["a"]` behaves like
+    /// `obj.a`.
+    ///
+    /// ```
+    /// use biome_js_parser::{JsParserOptions, parse_module};
+    ///
+    /// let parse = parse_module("obj[\"a\"]; obj[k] = 1;", JsParserOptions::default());
+    /// let statics: Vec<_> = parse
+    ///     .computed_member_accesses()
+    ///     .iter()
+    ///     .map(|access| access.is_static)
+    ///     .collect();
+    /// assert_eq!(statics, [true, false]);
+    /// ```
+    pub fn computed_member_accesses(&self) -> Vec<ComputedMemberAccess> {
+        self.syntax()
+            .descendants()
+            .filter_map(|node| {
+                let (object, key) =
+                    if let Some(expression) = JsComputedMemberExpression::cast_ref(&node) {
+                        (expression.object().ok()?, expression.member().ok()?)
+                    } else if let Some(assignment) = JsComputedMemberAssignment::cast_ref(&node) {
+                        (assignment.object().ok()?, assignment.member().ok()?)
+                    } else {
+                        return None;
+                    };
+
+                Some(ComputedMemberAccess {
+                    range: node.text_trimmed_range(),
+                    object_range: object.range(),
+                    key_range: key.range(),
+                    is_static: matches!(
+                        key,
+                        AnyJsExpression::AnyJsLiteralExpression(
+                            AnyJsLiteralExpression::JsStringLiteralExpression(_)
+                        )
+                    ),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns every `this` expression in source order, with the function or class that
+    /// binds it.
+    ///
+    /// Arrow functions don't bind `this`, so a `this` inside an arrow is bound by the closest
+    /// enclosing non-arrow function, method, or class field. `this` in a computed member name
+    /// is evaluated outside of the member and is bound by the member's surroundings.
+    ///
+    /// ```
+    /// use biome_js_parser::{JsParserOptions, ThisBinding, parse_module};
+    ///
+    /// let parse = parse_module("this; function f() { this; }", JsParserOptions::default());
+    /// let bindings: Vec<_> = parse.this_references().iter().map(|this| this.binding).collect();
+    /// assert_eq!(bindings, [ThisBinding::TopLevel, ThisBinding::Function]);
+    /// ```
+    pub fn this_references(&self) -> Vec<ThisReference> {
+        self.syntax()
+            .descendants()
+            .filter(|node| node.kind() == JsSyntaxKind::JS_THIS_EXPRESSION)
+            .map(|this| {
+                let mut in_arrow = false;
+                let mut previous = this.clone();
+
+                for ancestor in this.ancestors().skip(1) {
+                    if previous.kind() == JsSyntaxKind::JS_COMPUTED_MEMBER_NAME {
+                        previous = ancestor;
+                        continue;
+                    }
+
+                    let binding = match ancestor.kind() {
+                        JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION => {
+                            in_arrow = true;
+                            None
+                        }
+                        JsSyntaxKind::JS_FUNCTION_DECLARATION
+                        | JsSyntaxKind::JS_FUNCTION_EXPRESSION
+                        | JsSyntaxKind::JS_FUNCTION_EXPORT_DEFAULT_DECLARATION => {
+                            Some(ThisBinding::Function)
+                        }
+                        JsSyntaxKind::JS_METHOD_CLASS_MEMBER
+                        | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+                        | JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER
+                        | JsSyntaxKind::JS_GETTER_CLASS_MEMBER
+                        | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+                        | JsSyntaxKind::JS_SETTER_CLASS_MEMBER
+                        | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER => Some(ThisBinding::Method),
+                        JsSyntaxKind::JS_PROPERTY_CLASS_MEMBER
+                        | JsSyntaxKind::JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER => {
+                            Some(ThisBinding::ClassField)
+                        }
+                        _ => None,
+                    };
+
+                    if let Some(binding) = binding {
+                        return ThisReference {
+                            range: this.text_trimmed_range(),
+                            binding,
+                            binding_range: Some(ancestor.text_trimmed_range()),
+                            in_arrow,
+                        };
+                    }
+
+                    previous = ancestor;
+                }
+
+                ThisReference {
+                    range: this.text_trimmed_range(),
+                    binding: ThisBinding::TopLevel,
+                    binding_range: None,
+                    in_arrow,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the numeric literal expressions of the source, in source order, with their
+    /// source text and their value.
+    ///
+    /// ```
+    /// use biome_js_parser::{JsParserOptions, NumberValue, parse_module};
+    ///
+    /// let parse = parse_module("a = [0x1F, 1.5, 10n];", JsParserOptions::default());
+    /// let values: Vec<_> = parse.numeric_literals().into_iter().map(|literal| literal.value).collect();
+    /// assert_eq!(
+    ///     values,
+    ///     [NumberValue::Integer(31.0), NumberValue::Float(1.5), NumberValue::BigInt(Some(10))]
+    /// );
+    /// ```
+    pub fn numeric_literals(&self) -> Vec<NumericLiteral> {
+        self.syntax()
+            .descendants()
+            .filter_map(|node| {
+                let (token, is_bigint) = match node.kind() {
+                    JsSyntaxKind::JS_NUMBER_LITERAL_EXPRESSION => (
+                        JsNumberLiteralExpression::unwrap_cast(node).value_token(),
+                        false,
+                    ),
+                    JsSyntaxKind::JS_BIGINT_LITERAL_EXPRESSION => (
+                        JsBigintLiteralExpression::unwrap_cast(node).value_token(),
+                        true,
+                    ),
+                    _ => return None,
+                };
+
+                let token = token.ok()?;
+                let text = token.text_trimmed();
+                let value = if is_bigint {
+                    let (radix, digits) = split_into_radix_and_number(text.trim_end_matches('n'));
+                    NumberValue::BigInt(u128::from_str_radix(&digits, radix as u32).ok())
+                } else {
+                    let value = parse_js_number(text)?;
+                    let (radix, digits) = split_into_radix_and_number(text);
+                    if radix != 10 || digits.bytes().all(|byte| byte.is_ascii_digit()) {
+                        NumberValue::Integer(value)
+                    } else {
+                        NumberValue::Float(value)
+                    }
+                };
+
+                Some(NumericLiteral {
+                    text: text.to_string(),
+                    range: token.text_trimmed_range(),
+                    value,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns every class of the source, in source order, with the name, kind, and modifiers
+    /// of its members.
+    ///
+    /// Members without a name, such as static initialization blocks and index signatures, are
+    /// omitted. Private names like `#a` are reported with their `#` and a private visibility.
+    ///
+    /// ```
+    /// use biome_js_parser::{ClassMemberKind, JsParserOptions, parse_module};
+    ///
+    /// let parse = parse_module("class A { static b() {} }", JsParserOptions::default());
+    /// let classes = parse.class_members();
+    /// assert_eq!(classes[0].name.as_deref(), Some("A"));
+    /// assert_eq!(classes[0].members[0].name, "b");
+    /// assert_eq!(classes[0].members[0].kind, ClassMemberKind::Method);
+    /// assert!(classes[0].members[0].is_static);
+    /// ```
+    pub fn class_members(&self) -> Vec<ClassMembers> {
+        self.syntax()
+            .descendants()
+            .filter_map(AnyJsClass::cast)
+            .map(|class| ClassMembers {
+                name: class
+                    .id()
+                    .and_then(|id| id.as_js_identifier_binding()?.name_token().ok())
+                    .map(|name| name.text_trimmed().to_string()),
+                range: class.range(),
+                members: class.members().iter().filter_map(class_member).collect(),
+            })
+            .collect()
+    }
+
+    /// Detects the indentation unit of the source from the leading whitespace of its lines.
+    ///
+    /// Returns [DetectedIndentation::Tabs] if most indented lines start with a tab. Otherwise, returns
+    /// the most common increase of indentation between two consecutive lines. Returns `None`
+    /// if no line is indented.
+    ///
+    /// ```
+    /// use biome_js_parser::{DetectedIndentation, JsParserOptions, parse_module};
+    ///
+    /// let parse = parse_module("if (a) {\n\tb();\n}", JsParserOptions::default());
+    /// assert_eq!(parse.detect_indentation(), Some(DetectedIndentation::Tabs));
+    /// ```
+    pub fn detect_indentation(&self) -> Option<DetectedIndentation> {
+        let mut tab_lines = 0usize;
+        let mut space_lines = 0usize;
+        let mut previous_width = 0usize;
+        // Number of times the indentation increased by `index + 1` spaces
+        let mut increments: Vec<usize> = Vec::new();
+
+        for token in self.syntax().descendants_tokens(Direction::Next) {
+            let trivia: Vec<_> = token.leading_trivia().pieces().collect();
+            // Only the first token of a line has a newline in its leading trivia
+            let Some(newline) = trivia.iter().rposition(|piece| piece.is_newline()) else {
+                continue;
+            };
+            let indentation = trivia
+                .get(newline + 1)
+                .filter(|piece| piece.is_whitespace())
+                .map_or("", |piece| piece.text());
+
+            if indentation.starts_with('\t') {
+                tab_lines += 1;
+                continue;
+            }
+
+            let width = indentation.len();
+            if width > 0 {
+                space_lines += 1;
+            }
+            if width > previous_width {
+                let increment = width - previous_width;
+                if increments.len() < increment {
+                    increments.resize(increment, 0);
+                }
+                increments[increment - 1] += 1;
+            }
+            previous_width = width;
+        }
+
+        if tab_lines > space_lines {
+            return Some(DetectedIndentation::Tabs);
+        }
+
+        // On ties, the smaller increment wins
+        let (index, count) = increments
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, count)| **count)?;
+
+        // Widths that don't fit a `u8` aren't realistic indentation, saturate instead of wrapping
+        let width = u8::try_from(index + 1).unwrap_or(u8::MAX);
+        (*count > 0).then_some(DetectedIndentation::Spaces(width))
+    }
+}
+
+/// The indentation unit of a source, see [Parse::detect_indentation].
+///
+/// Unlike the formatter's indent style, it includes the number of spaces per level.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DetectedIndentation {
+    /// Lines are indented with tabs.
+    Tabs,
+    /// Lines are indented with the given number of spaces per level, at most [u8::MAX].
+    Spaces(u8),
+}
+
+/// The leading import statements of a module.
+///
+/// See [Parse::import_section].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ImportSection {
+    /// The import statements, in source order.
+    pub imports: Vec<JsImport>,
+    /// The offset at which the code following the imports begins, including its leading trivia.
+    pub end: TextSize,
+}
+
+/// A numeric literal, see [Parse::numeric_literals].
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumericLiteral {
+    /// The literal as written in the source, e.g. `0x1F` or `1_000`.
+    pub text: String,
+    /// The range of the literal.
+    pub range: TextRange,
+    /// The value of the literal.
+    pub value: NumberValue,
+}
+
+/// The value of a [NumericLiteral].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumberValue {
+    /// A literal without a fraction or an exponent, e.g. `1_000` or `0x1F`.
+    Integer(f64),
+    /// A decimal literal with a fraction or an exponent, e.g. `1.5` or `1e3`.
+    Float(f64),
+    /// A BigInt literal, e.g. `10n`. The value is `None` if it doesn't fit in a `u128`.
+    BigInt(Option<u128>),
+}
+
+/// A computed member access, such as `obj[key]`.
+///
+/// See [Parse::computed_member_accesses].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ComputedMemberAccess {
+    /// The range of the whole access, e.g. `obj[key]`.
+    pub range: TextRange,
+    /// The range of the accessed object, e.g. `obj`.
+    pub object_range: TextRange,
+    /// The range of the key expression, e.g. `key`.
+    pub key_range: TextRange,
+    /// Whether the key is a string literal, making the access effectively static.
+    pub is_static: bool,
+}
+
+/// A `this` expression and what binds it, see [Parse::this_references].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ThisReference {
+    /// The range of the `this` expression.
+    pub range: TextRange,
+    /// What binds this `this`.
+    pub binding: ThisBinding,
+    /// The range of the function, method, or class member that binds `this`, `None` at the
+    /// top level.
+    pub binding_range: Option<TextRange>,
+    /// Whether the `this` is used inside an arrow function nested in its binding.
+    pub in_arrow: bool,
+}
+
+/// What binds a `this` expression.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ThisBinding {
+    /// A function declaration or expression.
+    Function,
+    /// A class or object method, getter, setter, or constructor.
+    Method,
+    /// A class property initializer or a static initialization block.
+    ClassField,
+    /// The top level, where `this` is `undefined` in modules and the global object in scripts.
+    TopLevel,
+}
+
+/// The raw output of the parser, see [parse_retaining_events].
+#[derive(Debug, Clone)]
+pub struct ParseEvents {
+    /// The events emitted by the parser.
+    pub events: Vec<Event<JsSyntaxKind>>,
+    /// The trivia of the source, which a [LosslessTreeSink] needs to rebuild the tree.
+    pub trivia: Vec<Trivia>,
+}
+
+/// An optional chaining site, such as `a?.b` or `a?.()`.
+///
+/// See [Parse::optional_chains].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct OptionalChain {
+    /// Whether the `?.` guards a member access or a call.
+    pub kind: OptionalChainKind,
+    /// The range of the optional expression, e.g. `a?.b`.
+    pub range: TextRange,
+    /// The range of the `?.` token.
+    pub operator_range: TextRange,
+}
+
+/// What an optional chaining operator guards.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum OptionalChainKind {
+    /// A static or computed member access, e.g. `a?.b` or `a?.[b]`.
+    Member,
+    /// A call, e.g. `a?.()`.
+    Call,
+}
+
+/// A class and its members, see [Parse::class_members].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ClassMembers {
+    /// The name of the class, `None` for anonymous class expressions and default exports.
+    pub name: Option<String>,
+    /// The range of the class.
+    pub range: TextRange,
+    /// The named members of the class, in source order.
+    pub members: Vec<ClassMember>,
+}
+
+/// A named class member, see [Parse::class_members].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ClassMember {
+    /// The name of the member as written in the source, e.g. `a`, `#a`, or `[a]`.
+    pub name: String,
+    /// Whether the member is a constructor, a method, an accessor, or a field.
+    pub kind: ClassMemberKind,
+    /// Whether the member has a `static` modifier.
+    pub is_static: bool,
+    /// The visibility from a TypeScript accessibility modifier or a private name.
+    pub visibility: ClassMemberVisibility,
+    /// The range of the member.
+    pub range: TextRange,
+}
+
+/// The kind of a [ClassMember].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ClassMemberKind {
+    Constructor,
+    Method,
+    Getter,
+    Setter,
+    Field,
+}
+
+/// The visibility of a [ClassMember].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ClassMemberVisibility {
+    Public,
+    Protected,
+    /// A TypeScript `private` member or a member with a private name like `#a`.
+    Private,
+}
+
+fn class_member(member: AnyJsClassMember) -> Option<ClassMember> {
+    let kind = match &member {
+        AnyJsClassMember::JsConstructorClassMember(_)
+        | AnyJsClassMember::TsConstructorSignatureClassMember(_) => ClassMemberKind::Constructor,
+        AnyJsClassMember::JsMethodClassMember(_)
+        | AnyJsClassMember::TsMethodSignatureClassMember(_) => ClassMemberKind::Method,
+        AnyJsClassMember::JsGetterClassMember(_)
+        | AnyJsClassMember::TsGetterSignatureClassMember(_) => ClassMemberKind::Getter,
+        AnyJsClassMember::JsSetterClassMember(_)
+        | AnyJsClassMember::TsSetterSignatureClassMember(_) => ClassMemberKind::Setter,
+        AnyJsClassMember::JsPropertyClassMember(_)
+        | AnyJsClassMember::TsPropertySignatureClassMember(_)
+        | AnyJsClassMember::TsInitializedPropertySignatureClassMember(_) => ClassMemberKind::Field,
+        AnyJsClassMember::JsEmptyClassMember(_)
+        | AnyJsClassMember::JsStaticInitializationBlockClassMember(_)
+        | AnyJsClassMember::TsIndexSignatureClassMember(_)
+        | AnyJsClassMember::JsBogusMember(_) => return None,
+    };
+
+    let name = member.name().ok()??;
+    let mut visibility = if matches!(name, AnyJsClassMemberName::JsPrivateClassMemberName(_)) {
+        ClassMemberVisibility::Private
+    } else {
+        ClassMemberVisibility::Public
+    };
+    let name = match name {
+        AnyJsClassMemberName::JsLiteralMemberName(name) => name.name().ok()?.text().to_string(),
+        name => name.syntax().text_trimmed().to_string(),
+    };
+
+    let mut is_static = false;
+    let modifiers = member
+        .syntax()
+        .children()
+        .filter(|child| {
+            matches!(
+                child.kind(),
+                JsSyntaxKind::JS_CONSTRUCTOR_MODIFIER_LIST
+                    | JsSyntaxKind::JS_METHOD_MODIFIER_LIST
+                    | JsSyntaxKind::JS_PROPERTY_MODIFIER_LIST
+                    | JsSyntaxKind::TS_METHOD_SIGNATURE_MODIFIER_LIST
+                    | JsSyntaxKind::TS_PROPERTY_SIGNATURE_MODIFIER_LIST
+            )
+        })
+        .flat_map(|list| list.children());
+
+    for modifier in modifiers {
+        if modifier.kind() == JsSyntaxKind::JS_STATIC_MODIFIER {
+            is_static = true;
+        } else if let Some(accessibility) = TsAccessibilityModifier::cast(modifier) {
+            if accessibility.is_private() {
+                visibility = ClassMemberVisibility::Private;
+            } else if accessibility.is_protected() {
+                visibility = ClassMemberVisibility::Protected;
+            }
+        }
+    }
+
+    Some(ClassMember {
+        name,
+        kind,
+        is_static,
+        visibility,
+        range: member.range(),
+    })
+}
+
+fn trailing_comma<L>(list: &L, context: TrailingCommaContext) -> Option<TrailingComma>
+where
+    L: AstNode<Language = JsLanguage> + AstSeparatedList<Language = JsLanguage>,
+{
+    if list.is_empty() {
+        return None;
+    }
+
+    Some(TrailingComma {
+        context,
+        range: list.range(),
+        has_trailing_comma: list.trailing_separator().is_some(),
+    })
+}
+
+/// The kind of comma-separated construct reported by [Parse::trailing_comma_report].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TrailingCommaContext {
+    /// The elements of an array literal, e.g. `[a, b]`.
+    Array,
+    /// The members of an object literal, e.g. `{ a, b }`.
+    Object,
+    /// The parameters of a function, method, or constructor, e.g. `function f(a, b) {}`.
+    Parameters,
+    /// The arguments of a call or `new` expression, e.g. `f(a, b)`.
+    Arguments,
+    /// The named specifiers of an import, e.g. `import { a, b } from "c"`.
+    Imports,
+}
+
+/// A comma-separated construct and whether it ends with a trailing comma.
+///
+/// See [Parse::trailing_comma_report].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TrailingComma {
+    /// The kind of construct.
+    pub context: TrailingCommaContext,
+    /// The range of the construct's elements, trailing comma included.
+    pub range: TextRange,
+    /// Whether the last element is followed by a comma.
+    pub has_trailing_comma: bool,
+}
+
+/// A chain of `+` operations with at least one string literal operand.
+///
+/// See [Parse::string_concat_chains].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct StringConcatChain {
+    /// The range of the whole chain, e.g. `"a" + x + "b"`.
+    pub range: TextRange,
+    /// The operands of the chain, from left to right.
+    pub operands: Vec<AnyJsExpression>,
+}
+
+/// Returns the statements and module items of a script or module root.
+fn top_level_statements(root: &JsSyntaxNode) -> impl Iterator<Item = JsSyntaxNode> {
+    root.children()
+        .filter(|list| {
+            matches!(
+                list.kind(),
+                JsSyntaxKind::JS_MODULE_ITEM_LIST | JsSyntaxKind::JS_STATEMENT_LIST
+            )
+        })
+        .flat_map(|list| list.children())
+}
+
+fn is_plus(expression: &JsBinaryExpression) -> bool {
+    matches!(expression.operator(), Ok(JsBinaryOperator::Plus))
+}
+
+fn statement_has_side_effects(statement: &AnyJsStatement) -> bool {
+    match statement {
+        AnyJsStatement::JsClassDeclaration(declaration) => {
+            class_has_side_effects(&declaration.clone().into())
+        }
+        AnyJsStatement::JsEmptyStatement(_)
+        | AnyJsStatement::JsFunctionDeclaration(_)
+        | AnyJsStatement::TsDeclareFunctionDeclaration(_)
+        | AnyJsStatement::TsDeclareStatement(_)
+        | AnyJsStatement::TsInterfaceDeclaration(_)
+        | AnyJsStatement::TsTypeAliasDeclaration(_) => false,
+        AnyJsStatement::JsVariableStatement(statement) => {
+            statement.declaration().map_or(true, |declaration| {
+                declaration_has_side_effects(&declaration)
+            })
+        }
+        AnyJsStatement::JsExpressionStatement(statement) => statement
+            .expression()
+            .map_or(true, |expression| !is_side_effect_free(&expression)),
+        _ => true,
+    }
+}
+
+fn declaration_has_side_effects(declaration: &JsVariableDeclaration) -> bool {
+    declaration.declarators().iter().any(|declarator| {
+        let Ok(declarator) = declarator else {
+            return true;
+        };
+        declarator.initializer().is_some_and(|initializer| {
+            initializer
+                .expression()
+                .map_or(true, |expression| !is_side_effect_free(&expression))
+        })
+    })
+}
+
+/// Returns `true` if evaluating `expression` can't have side effects.
+fn is_side_effect_free(expression: &AnyJsExpression) -> bool {
+    match expression {
+        AnyJsExpression::AnyJsLiteralExpression(_)
+        | AnyJsExpression::JsArrowFunctionExpression(_)
+        | AnyJsExpression::JsFunctionExpression(_)
+        | AnyJsExpression::JsIdentifierExpression(_) => true,
+        AnyJsExpression::JsParenthesizedExpression(expression) => expression
+            .expression()
+            .is_ok_and(|expression| is_side_effect_free(&expression)),
+        AnyJsExpression::JsClassExpression(expression) => {
+            !class_has_side_effects(&expression.clone().into())
+        }
+        AnyJsExpression::JsCallExpression(_) | AnyJsExpression::JsNewExpression(_) => {
+            has_pure_annotation(expression.syntax())
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if defining `class` can have side effects.
+///
+/// Decorators are calls, and static blocks run when the class is defined. The `extends` clause,
+/// computed member names, and static field initializers are evaluated when the class is defined.
+/// Computed member names are converted to property keys, which can call `toString`, unless
+/// they're literals.
+fn class_has_side_effects(class: &AnyJsClass) -> bool {
+    if class
+        .syntax()
+        .descendants()
+        .any(|node| node.kind() == JsSyntaxKind::JS_DECORATOR)
+    {
+        return true;
+    }
+
+    let extends_has_side_effects = class.extends_clause().is_some_and(|clause| {
+        clause
+            .super_class()
+            .map_or(true, |super_class| !is_side_effect_free(&super_class))
+    });
+
+    extends_has_side_effects
+        || class
+            .members()
+            .iter()
+            .any(|member| class_member_has_side_effects(&member))
+}
+
+fn class_member_has_side_effects(member: &AnyJsClassMember) -> bool {
+    let computed_name_has_side_effects = match member.name() {
+        Ok(Some(AnyJsClassMemberName::JsComputedMemberName(name))) => !matches!(
+            name.expression(),
+            Ok(AnyJsExpression::AnyJsLiteralExpression(_))
+        ),
+        Ok(_) => false,
+        Err(_) => true,
+    };
+
+    computed_name_has_side_effects
+        || match member {
+            AnyJsClassMember::JsStaticInitializationBlockClassMember(_)
+            | AnyJsClassMember::JsBogusMember(_) => true,
+            AnyJsClassMember::JsPropertyClassMember(property) => {
+                let is_static = property
+                    .modifiers()
+                    .iter()
+                    .any(|modifier| matches!(modifier, AnyJsPropertyModifier::JsStaticModifier(_)));
+
+                is_static
+                    && property.value().is_some_and(|initializer| {
+                        initializer
+                            .expression()
+                            .map_or(true, |expression| !is_side_effect_free(&expression))
+                    })
+            }
+            _ => false,
+        }
+}
+
+/// Returns `true` if `node` is preceded by a `@__PURE__` or `#__PURE__` annotation comment.
+fn has_pure_annotation(node: &JsSyntaxNode) -> bool {
+    let Some(first_token) = node.first_token() else {
+        return false;
+    };
+
+    // A comment on the same line as the previous token is part of its trailing trivia.
+    let trailing = first_token
+        .prev_token()
+        .map(|token| token.trailing_trivia().pieces().collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    trailing
+        .into_iter()
+        .chain(first_token.leading_trivia().pieces())
+        .filter(|piece| piece.is_comments())
+        .any(|piece| {
+            let text = piece.text();
+            text.contains("@__PURE__") || text.contains("#__PURE__")
+        })
 }
 
 impl<T: AstNode<Language = JsLanguage>> Parse<T> {
@@ -108,22 +1617,43 @@ impl<T: AstNode<Language = JsLanguage>> Parse<T> {
         if !self.errors.iter().any(|d| d.is_error()) {
             Ok(self.tree())
         } else {
-            Err(self.errors)
+            Err(self
+                .errors
+                .into_iter()
+                .filter(|diagnostic| diagnostic.is_error())
+                .collect())
         }
     }
 }
 
-fn parse_common(
+/// Parses `text` and returns the events, the diagnostics, the trivia, whether the parser
+/// skipped syntax that exceeded the maximum nesting depth, and whether the program has a
+/// top-level await.
+pub(crate) fn parse_common(
     text: &str,
     source_type: JsFileSource,
     options: JsParserOptions,
-) -> (Vec<Event<JsSyntaxKind>>, Vec<ParseDiagnostic>, Vec<Trivia>) {
+) -> (
+    Vec<Event<JsSyntaxKind>>,
+    Vec<ParseDiagnostic>,
+    Vec<Trivia>,
+    bool,
+    bool,
+) {
     let mut parser = JsParser::new(text, source_type, options);
     syntax::program::parse(&mut parser);
 
+    let max_depth_reached = parser.max_depth_reached();
+    let has_top_level_await = parser.has_top_level_await();
     let (events, trivia, errors) = parser.finish();
 
-    (events, errors, trivia)
+    (
+        events,
+        errors,
+        trivia,
+        max_depth_reached,
+        has_top_level_await,
+    )
 }
 
 /// Parse text into a [`Parse`](Parse) which can then be turned into an untyped root [`JsSyntaxNode`](JsSyntaxNode).
@@ -237,6 +1767,528 @@ pub fn parse(text: &str, source_type: JsFileSource, options: JsParserOptions) ->
     parse_js_with_cache(text, source_type, options, &mut cache)
 }
 
+/// Parses the provided string as a single expression, such as the input of a devtools console
+/// or a template for a code transformation.
+///
+/// The root of the tree is a [JsExpressionSnipped] whose text is the whole source, like the
+/// roots of [parse]. `source_type` decides whether the expression may contain TypeScript or
+/// JSX and whether it's in strict mode. Tokens after the expression are reported and wrapped
+/// together with the expression in a bogus expression.
+///
+/// ```
+/// use biome_js_parser::{parse_expression, JsParserOptions};
+/// use biome_js_syntax::{AnyJsExpression, JsFileSource};
+///
+/// let parse = parse_expression("a + b", JsFileSource::js_module(), JsParserOptions::default());
+/// assert!(!parse.has_errors());
+/// assert!(matches!(
+///     parse.tree().expression(),
+///     Ok(AnyJsExpression::JsBinaryExpression(_))
+/// ));
+///
+/// let parse = parse_expression("a; b", JsFileSource::js_module(), JsParserOptions::default());
+/// assert!(parse.has_errors());
+/// assert_eq!(parse.syntax().text(), "a; b");
+/// ```
+pub fn parse_expression(
+    text: &str,
+    source_type: JsFileSource,
+    options: JsParserOptions,
+) -> Parse<JsExpressionSnipped> {
+    let mut parser = JsParser::new(text, source_type, options);
+    syntax::program::parse_expression_snippet(&mut parser);
+
+    let max_depth_reached = parser.max_depth_reached();
+    let has_top_level_await = parser.has_top_level_await();
+    let (events, trivia, errors) = parser.finish();
+
+    let mut tree_sink = JsLosslessTreeSink::new(text, &trivia);
+    biome_parser::event::process(&mut tree_sink, events, errors);
+    let (green, parse_errors) = tree_sink.finish();
+    Parse::new(green, parse_errors)
+        .with_source_type(source_type)
+        .with_max_depth_reached(max_depth_reached)
+        .with_top_level_await(has_top_level_await)
+}
+
+/// Parses the provided string as a single TypeScript type, such as the input of a type
+/// playground or a template for a code transformation.
+///
+/// The root of the tree is a [TsTypeSnipped] whose text is the whole source. Types are only
+/// valid if `source_type` supports type annotations, like TypeScript and Flow files. Tokens
+/// after the type are reported and wrapped together with the type in a bogus type.
+///
+/// ```
+/// use biome_js_parser::{parse_type, JsParserOptions};
+/// use biome_js_syntax::{AnyTsType, JsFileSource};
+///
+/// let parse = parse_type("Array<string> | null", JsFileSource::ts(), JsParserOptions::default());
+/// assert!(!parse.has_errors());
+/// assert!(matches!(parse.tree().ty(), Ok(AnyTsType::TsUnionType(_))));
+///
+/// let parse = parse_type("string", JsFileSource::js_module(), JsParserOptions::default());
+/// assert!(parse.has_errors());
+/// ```
+pub fn parse_type(
+    text: &str,
+    source_type: JsFileSource,
+    options: JsParserOptions,
+) -> Parse<TsTypeSnipped> {
+    let mut parser = JsParser::new(text, source_type, options);
+    syntax::program::parse_type_snippet(&mut parser);
+
+    let max_depth_reached = parser.max_depth_reached();
+    let (events, trivia, errors) = parser.finish();
+
+    let mut tree_sink = JsLosslessTreeSink::new(text, &trivia);
+    biome_parser::event::process(&mut tree_sink, events, errors);
+    let (green, parse_errors) = tree_sink.finish();
+    Parse::new(green, parse_errors)
+        .with_source_type(source_type)
+        .with_max_depth_reached(max_depth_reached)
+}
+
+/// Parses the provided string and feeds the resulting events to `sink` instead of building a
+/// syntax tree.
+///
+/// This is useful for tools that only need to observe the structure of the source, like counting
+/// constructs, without paying for the syntax tree. The sink receives the diagnostics through
+/// [TreeSink::errors]. Neither trivia nor the end of file token are reported to the sink: a token
+/// ends at the offset passed to [TreeSink::token] and any gap with the previous token is trivia.
+///
+/// ```
+/// use biome_js_parser::{JsParserOptions, parse_with_visitor};
+/// use biome_js_syntax::{JsFileSource, JsSyntaxKind};
+/// use biome_parser::prelude::ParseDiagnostic;
+/// use biome_parser::tree_sink::TreeSink;
+/// use biome_rowan::TextSize;
+///
+/// #[derive(Default)]
+/// struct TokenCounter(usize);
+///
+/// impl TreeSink for TokenCounter {
+///     type Kind = JsSyntaxKind;
+///
+///     fn token(&mut self, _kind: JsSyntaxKind, _end: TextSize) {
+///         self.0 += 1;
+///     }
+///     fn start_node(&mut self, _kind: JsSyntaxKind) {}
+///     fn finish_node(&mut self) {}
+///     fn errors(&mut self, _errors: Vec<ParseDiagnostic>) {}
+/// }
+///
+/// let mut counter = TokenCounter::default();
+/// parse_with_visitor("a + b", JsFileSource::js_module(), JsParserOptions::default(), &mut counter);
+/// // `a`, `+`, and `b`
+/// assert_eq!(counter.0, 3);
+/// ```
+pub fn parse_with_visitor<S>(
+    text: &str,
+    source_type: JsFileSource,
+    options: JsParserOptions,
+    sink: &mut S,
+) where
+    S: TreeSink<Kind = JsSyntaxKind>,
+{
+    let (events, errors, _, _, _) = parse_common(text, source_type, options);
+    biome_parser::event::process(sink, events, errors);
+}
+
+/// Parses the provided string like [parse], and retains the parser events so that they can be
+/// retrieved with [Parse::into_events].
+///
+/// The events and the trivia are kept in memory for as long as the [Parse] lives, in addition
+/// to the tree. They take about as much memory as the tree itself, so prefer [parse] unless the
+/// events are needed.
+///
+/// ```
+/// use biome_js_parser::{JsParserOptions, parse_retaining_events};
+/// use biome_js_syntax::JsFileSource;
+///
+/// let parse = parse_retaining_events("a + b", JsFileSource::js_module(), JsParserOptions::default());
+/// let events = parse.into_events().unwrap();
+/// assert!(!events.events.is_empty());
+/// ```
+pub fn parse_retaining_events(
+    text: &str,
+    source_type: JsFileSource,
+    options: JsParserOptions,
+) -> Parse<AnyJsRoot> {
+    let (events, errors, trivia, max_depth_reached, has_top_level_await) =
+        parse_common(text, source_type, options);
+    let mut tree_sink = JsLosslessTreeSink::new(text, &trivia);
+    biome_parser::event::process(&mut tree_sink, events.clone(), errors);
+    let (green, parse_errors) = tree_sink.finish();
+
+    let mut parse = Parse::new(green, parse_errors)
+        .with_source_type(source_type)
+        .with_max_depth_reached(max_depth_reached)
+        .with_top_level_await(has_top_level_await);
+    parse.events = Some(ParseEvents { events, trivia });
+    parse
+}
+
+/// Parses a fragment of a larger document, such as the content of a `<script>` block of an HTML
+/// or Vue file, that starts at `offset` in the document.
+///
+/// The lexer only sees `text`, but the ranges of the returned tree and of the diagnostics are
+/// relative to the start of the enclosing document. `offset` is a byte offset and must be on a
+/// UTF-8 character boundary of the document.
+///
+/// ```
+/// use biome_js_parser::{JsParserOptions, parse_with_offset};
+/// use biome_js_syntax::JsFileSource;
+/// use biome_rowan::TextSize;
+///
+/// let document = "<script>let a = 1;</script>";
+/// let parsed = parse_with_offset(
+///     &document[8..18],
+///     TextSize::from(8),
+///     JsFileSource::js_module(),
+///     JsParserOptions::default(),
+/// );
+/// assert_eq!(&document[parsed.syntax().text_range()], "let a = 1;");
+/// ```
+pub fn parse_with_offset(
+    text: &str,
+    offset: TextSize,
+    source_type: JsFileSource,
+    options: JsParserOptions,
+) -> Parse<AnyJsRoot> {
+    let parse = parse(text, source_type, options);
+    let root = parse.syntax().clone_subtree_at(offset);
+    let max_depth_reached = parse.max_depth_reached();
+    let has_top_level_await = parse.has_top_level_await();
+    let diagnostics = parse
+        .into_diagnostics()
+        .into_iter()
+        .map(|diagnostic| diagnostic.with_offset(offset))
+        .collect();
+
+    Parse::new(root, diagnostics)
+        .with_source_type(source_type)
+        .with_max_depth_reached(max_depth_reached)
+        .with_top_level_await(has_top_level_await)
+}
+
+/// Parses the source of an existing `lexer`, for example one that was already used to look
+/// ahead in the source, instead of creating a new lexer.
+///
+/// The lexer must be positioned at the start of its source, meaning that it hasn't returned any
+/// token yet. Tokens that it already lexed ahead are reused by the parser. The lexer's own
+/// settings, like the confusable identifiers check, take precedence over the ones in `options`.
+///
+/// Returns an error if the lexer isn't positioned at the start of its source, or if it lexed
+/// JSX or TypeScript tokens that aren't valid for `source_type`.
+///
+/// ```
+/// use biome_js_parser::{parse_from_lexer, JsLexer, JsParserOptions};
+/// use biome_js_syntax::{JsFileSource, JsSyntaxKind};
+/// use biome_parser::lexer::BufferedLexer;
+///
+/// let source = "let a = 1;";
+/// let mut lexer = BufferedLexer::new(JsLexer::from_str(source));
+/// let kinds: Vec<_> = lexer.lookahead_iter().map(|token| token.kind()).collect();
+/// assert_eq!(kinds.last(), Some(&JsSyntaxKind::EOF));
+///
+/// let parsed =
+///     parse_from_lexer(lexer, JsFileSource::js_module(), JsParserOptions::default()).unwrap();
+/// assert_eq!(parsed.syntax().text(), source);
+/// assert!(!parsed.has_errors());
+/// ```
+pub fn parse_from_lexer(
+    lexer: BufferedLexer<JsSyntaxKind, JsLexer<'_>>,
+    source_type: JsFileSource,
+    options: JsParserOptions,
+) -> Result<Parse<AnyJsRoot>, ParseDiagnostic> {
+    if lexer.current() != JsSyntaxKind::TOMBSTONE {
+        return Err(ParseDiagnostic::new(
+            "The lexer must be positioned at the start of its source.",
+            lexer.current_range(),
+        ));
+    }
+
+    if lexer.lexer().has_lexed_jsx() && !source_type.is_jsx() {
+        return Err(ParseDiagnostic::new(
+            "The lexer lexed JSX tokens but the source type doesn't support JSX.",
+            TextRange::default(),
+        ));
+    }
+
+    if lexer.lexer().has_lexed_typescript() && !source_type.is_typescript() {
+        return Err(ParseDiagnostic::new(
+            "The lexer lexed TypeScript tokens but the source type isn't TypeScript.",
+            TextRange::default(),
+        ));
+    }
+
+    let text = lexer.source();
+    let mut parser = JsParser::from_buffered_lexer(lexer, source_type, options);
+    syntax::program::parse(&mut parser);
+    let max_depth_reached = parser.max_depth_reached();
+    let has_top_level_await = parser.has_top_level_await();
+    let (events, trivia, errors) = parser.finish();
+
+    let mut tree_sink = JsLosslessTreeSink::new(text, &trivia);
+    biome_parser::event::process(&mut tree_sink, events, errors);
+    let (green, parse_errors) = tree_sink.finish();
+    Ok(Parse::new(green, parse_errors)
+        .with_source_type(source_type)
+        .with_max_depth_reached(max_depth_reached)
+        .with_top_level_await(has_top_level_await))
+}
+
+/// Checks whether the provided string is a valid EcmaScript program, without building a
+/// syntax tree.
+///
+/// The parser stops parsing statements once it reports the first error, and returns the
+/// diagnostics collected so far. This is faster than [parse] for sources with syntax errors
+/// because the rest of the source is skipped, and faster for valid sources because no tree is
+/// built. Lexer diagnostics, like unterminated strings, don't stop the parser but are returned
+/// too.
+///
+/// ```
+/// use biome_js_parser::parse_validate;
+/// use biome_js_syntax::JsFileSource;
+///
+/// assert!(parse_validate("let a = 1;", JsFileSource::js_module()).is_ok());
+///
+/// let diagnostics = parse_validate("let a = ;", JsFileSource::js_module()).unwrap_err();
+/// assert_eq!(diagnostics.len(), 1);
+/// ```
+pub fn parse_validate(text: &str, source_type: JsFileSource) -> Result<(), Vec<ParseDiagnostic>> {
+    let mut parser = JsParser::new(text, source_type, JsParserOptions::default()).with_fail_fast();
+    syntax::program::parse(&mut parser);
+    let (_, _, diagnostics) = parser.finish();
+
+    if diagnostics.iter().any(|diagnostic| diagnostic.is_error()) {
+        Err(diagnostics)
+    } else {
+        Ok(())
+    }
+}
+
+/// Parses the provided string as a EcmaScript program, detecting whether it's a script or a
+/// module if the module kind of `source_type` is [ModuleKind::Unknown].
+///
+/// This is the same as [parse], which resolves [ModuleKind::Unknown] too. The source is a module
+/// if it contains a top-level `import` or `export` declaration, or an `import.meta` expression.
+/// Dynamic `import()` calls are allowed in scripts too. The detection only scans the tokens of
+/// the source, so it's much cheaper than parsing the source twice.
+///
+/// Use [Parse::module_kind] to retrieve the chosen module kind.
+///
+/// ### Examples
+///
+/// ```
+/// use biome_js_parser::{JsParserOptions, parse_any};
+/// use biome_js_syntax::{JsFileSource, ModuleKind};
+///
+/// let source_type = JsFileSource::js_module().with_module_kind(ModuleKind::Unknown);
+///
+/// let parsed = parse_any("export {};", source_type, JsParserOptions::default());
+/// assert_eq!(parsed.module_kind(), ModuleKind::Module);
+///
+/// let parsed = parse_any("import('./a.js');", source_type, JsParserOptions::default());
+/// assert_eq!(parsed.module_kind(), ModuleKind::Script);
+/// ```
+pub fn parse_any(
+    text: &str,
+    source_type: JsFileSource,
+    options: JsParserOptions,
+) -> Parse<AnyJsRoot> {
+    parse(text, source_type, options)
+}
+
+/// A file that [parse_many] skipped.
+#[derive(Debug)]
+pub enum ParseManyError {
+    /// The source type couldn't be deduced from the path of the file.
+    FileSource(FileSourceError),
+    /// The parser panicked. Holds the panic message, if it was a string.
+    Panic(Option<String>),
+}
+
+/// The result of [parse_many].
+#[derive(Debug, Default)]
+pub struct ParsedFiles {
+    /// The parsed files, in input order.
+    pub parsed: Vec<(PathBuf, Parse<AnyJsRoot>)>,
+    /// The skipped files, in input order.
+    pub errors: Vec<(PathBuf, ParseManyError)>,
+}
+
+/// The parts of a [Parse] that can be sent across threads.
+struct SendParse {
+    root: SendNode,
+    errors: Vec<ParseDiagnostic>,
+    source_type: JsFileSource,
+    max_depth_reached: bool,
+    has_top_level_await: bool,
+}
+
+/// Parses many files, deducing the source type of each file from its path.
+///
+/// Files with an unknown extension, and files the parser panics on, are reported in
+/// [ParsedFiles::errors] rather than aborting the batch. With the `rayon` feature, the files
+/// are parsed in parallel on the global thread pool.
+///
+/// ```
+/// use biome_js_parser::{parse_many, JsParserOptions};
+/// use std::path::PathBuf;
+///
+/// let files = parse_many(
+///     vec![
+///         (PathBuf::from("a.ts"), "let a: number = 1;".to_string()),
+///         (PathBuf::from("b.txt"), "hello".to_string()),
+///     ],
+///     &JsParserOptions::default(),
+/// );
+///
+/// assert_eq!(files.parsed.len(), 1);
+/// assert_eq!(files.errors.len(), 1);
+/// ```
+pub fn parse_many<I>(inputs: I, options: &JsParserOptions) -> ParsedFiles
+where
+    I: IntoIterator<Item = (PathBuf, String)>,
+{
+    #[cfg(feature = "rayon")]
+    let results: Vec<_> = {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+        let inputs: Vec<_> = inputs.into_iter().collect();
+        inputs
+            .into_par_iter()
+            .map(|(path, text)| {
+                let result = parse_file(&path, &text, options);
+                (path, result)
+            })
+            .collect()
+    };
+
+    #[cfg(not(feature = "rayon"))]
+    let results = inputs.into_iter().map(|(path, text)| {
+        let result = parse_file(&path, &text, options);
+        (path, result)
+    });
+
+    collect_parsed_files(results)
+}
+
+fn parse_file(
+    path: &Path,
+    text: &str,
+    options: &JsParserOptions,
+) -> Result<SendParse, ParseManyError> {
+    let source_type = JsFileSource::try_from(path).map_err(ParseManyError::FileSource)?;
+
+    let parse = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        parse(text, source_type, options.clone())
+    }))
+    .map_err(|payload| {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => Some(*message),
+            Err(payload) => payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string()),
+        };
+        ParseManyError::Panic(message)
+    })?;
+
+    Ok(SendParse {
+        root: parse
+            .root
+            .as_send()
+            .expect("the root of a parse to be sendable"),
+        errors: parse.errors,
+        source_type,
+        max_depth_reached: parse.max_depth_reached,
+        has_top_level_await: parse.has_top_level_await,
+    })
+}
+
+fn collect_parsed_files(
+    results: impl IntoIterator<Item = (PathBuf, Result<SendParse, ParseManyError>)>,
+) -> ParsedFiles {
+    let mut files = ParsedFiles::default();
+
+    for (path, result) in results {
+        match result {
+            Ok(parse) => {
+                let root = parse
+                    .root
+                    .into_node::<JsLanguage>()
+                    .expect("a JavaScript syntax tree");
+                let parse = Parse::new(root, parse.errors)
+                    .with_source_type(parse.source_type)
+                    .with_max_depth_reached(parse.max_depth_reached)
+                    .with_top_level_await(parse.has_top_level_await);
+                files.parsed.push((path, parse));
+            }
+            Err(error) => files.errors.push((path, error)),
+        }
+    }
+
+    files
+}
+
+/// Scans the tokens of `text` for top-level `import` and `export` declarations, and
+/// `import.meta` expressions.
+///
+/// This is a best-effort detection: the scan doesn't know the context of a `/` and always
+/// lexes it as a division rather than a regular expression.
+pub(crate) fn detect_module_kind(text: &str) -> ModuleKind {
+    let mut lexer = JsLexer::from_str(text);
+    // For each open curly brace, whether it's a template element `${`.
+    let mut braces = Vec::new();
+    let mut in_template = false;
+    let mut previous = JsSyntaxKind::EOF;
+    let mut keyword_at_top_level = None;
+
+    loop {
+        let context = if in_template {
+            JsLexContext::TemplateElement { tagged: false }
+        } else {
+            JsLexContext::Regular
+        };
+        let kind = lexer.next_token(context);
+
+        if kind.is_trivia() {
+            continue;
+        }
+
+        if let Some((keyword, is_top_level)) = keyword_at_top_level.take() {
+            match (keyword, kind) {
+                // `import.meta`
+                (T![import], T![.]) => return ModuleKind::Module,
+                // `import()` calls, and methods or properties named `import` or `export`
+                (_, T!['('] | T![:]) => {}
+                _ if is_top_level => return ModuleKind::Module,
+                _ => {}
+            }
+        }
+
+        match kind {
+            JsSyntaxKind::EOF => break,
+            T![import] | T![export] if !matches!(previous, T![.] | T![?.]) => {
+                keyword_at_top_level = Some((kind, braces.is_empty()));
+            }
+            T!['{'] => braces.push(false),
+            JsSyntaxKind::DOLLAR_CURLY => {
+                braces.push(true);
+                in_template = false;
+            }
+            T!['}'] => in_template = braces.pop().unwrap_or_default(),
+            T!['`'] => in_template = !in_template,
+            _ => {}
+        }
+
+        previous = kind;
+    }
+
+    ModuleKind::Script
+}
+
 /// Parses the provided string as a EcmaScript program using the provided syntax features and node cache.
 ///
 /// ### Examples
@@ -264,10 +2316,14 @@ pub fn parse_js_with_cache(
     cache: &mut NodeCache,
 ) -> Parse<AnyJsRoot> {
     tracing::debug_span!("parse").in_scope(move || {
-        let (events, errors, tokens) = parse_common(text, source_type, options);
+        let (events, errors, tokens, max_depth_reached, has_top_level_await) =
+            parse_common(text, source_type, options);
         let mut tree_sink = JsLosslessTreeSink::with_cache(text, &tokens, cache);
         biome_parser::event::process(&mut tree_sink, events, errors);
         let (green, parse_errors) = tree_sink.finish();
         Parse::new(green, parse_errors)
+            .with_source_type(source_type)
+            .with_max_depth_reached(max_depth_reached)
+            .with_top_level_await(has_top_level_await)
     })
 }
@@ -7,7 +7,7 @@ use biome_parser::*;
 pub(crate) mod rewrite_parser;
 pub(crate) mod single_token_parse_recovery;
 
-use crate::lexer::JsReLexContext;
+use crate::lexer::{JsLexer, JsReLexContext};
 pub(crate) use crate::parser::parse_recovery::{
     ParseRecoveryTokenSet, RecoveryError, RecoveryResult,
 };
@@ -16,12 +16,15 @@ use crate::state::{ChangeParserState, ParserStateGuard};
 use crate::token_source::JsTokenSourceCheckpoint;
 use crate::*;
 use crate::{state::JsParserStateCheckpoint, token_source::JsTokenSource};
+use biome_diagnostics::location::AsSpan;
 use biome_js_syntax::{
     JsFileSource,
     JsSyntaxKind::{self},
+    ModuleKind, T,
 };
-use biome_parser::diagnostic::merge_diagnostics;
+use biome_parser::diagnostic::{merge_diagnostics, truncate_diagnostics};
 use biome_parser::event::Event;
+use biome_parser::lexer::BufferedLexer;
 use biome_parser::token_source::Trivia;
 use biome_parser::{ParserContext, ParserContextCheckpoint};
 pub(crate) use parsed_syntax::ParsedSyntax;
@@ -36,22 +39,161 @@ pub struct JsParser<'source> {
     context: ParserContext<JsSyntaxKind>,
     source: JsTokenSource<'source>,
     options: JsParserOptions,
+    /// Whether to stop parsing statements after the first error, see [crate::parse_validate].
+    fail_fast: bool,
+    /// Whether the parser skipped syntax that exceeded the maximum nesting depth.
+    max_depth_reached: bool,
 }
 
 impl<'source> JsParser<'source> {
     /// Creates a new parser that parses the `source`.
     pub fn new(source: &'source str, source_type: JsFileSource, options: JsParserOptions) -> Self {
-        let source = JsTokenSource::from_str(source);
+        let lexer = JsLexer::from_str(source)
+            .with_confusable_identifiers_check(options.check_confusable_identifiers)
+            .with_lone_surrogates_check(options.check_lone_surrogates);
+
+        Self::with_token_source(JsTokenSource::from_lexer(lexer), source_type, options)
+    }
+
+    /// Creates a new parser that parses the source of `lexer`, see [crate::parse_from_lexer].
+    pub(crate) fn from_buffered_lexer(
+        lexer: BufferedLexer<JsSyntaxKind, JsLexer<'source>>,
+        source_type: JsFileSource,
+        options: JsParserOptions,
+    ) -> Self {
+        Self::with_token_source(
+            JsTokenSource::from_buffered_lexer(lexer),
+            source_type,
+            options,
+        )
+    }
+
+    fn with_token_source(
+        source: JsTokenSource<'source>,
+        source_type: JsFileSource,
+        options: JsParserOptions,
+    ) -> Self {
+        let source_type = if source_type.module_kind() == ModuleKind::Unknown {
+            source_type.with_module_kind(crate::parse::detect_module_kind(source.text()))
+        } else {
+            source_type
+        };
 
         JsParser {
             state: JsParserState::new(&source_type),
             source_type,
-            context: ParserContext::default(),
+            context: ParserContext::default().with_max_diagnostics(options.max_diagnostics),
             source,
             options,
+            fail_fast: false,
+            max_depth_reached: false,
         }
     }
 
+    /// Makes the parser stop parsing statements once it reported an error.
+    pub(crate) fn with_fail_fast(mut self) -> Self {
+        self.fail_fast = true;
+        self
+    }
+
+    /// Returns `true` if the parser should stop parsing statements because it runs in fail
+    /// fast mode and reported an error.
+    ///
+    /// The parser never stops while parsing speculatively, because it may rewind the error.
+    pub(crate) fn should_stop(&self) -> bool {
+        self.fail_fast
+            && !self.state.speculative_parsing
+            && self
+                .context
+                .diagnostics()
+                .iter()
+                .any(|diagnostic| diagnostic.is_error())
+    }
+
+    /// Returns `true` if the parser skipped syntax that exceeded the maximum nesting depth.
+    pub(crate) fn max_depth_reached(&self) -> bool {
+        self.max_depth_reached
+    }
+
+    /// Returns `true` if the parser parsed an `await` outside of any function.
+    pub(crate) fn has_top_level_await(&self) -> bool {
+        self.state.has_top_level_await
+    }
+
+    /// Parses a statement or expression with `parse`, one nesting level deeper.
+    ///
+    /// If the syntax is nested deeper than [JsParserOptions::max_nesting_depth], the parser
+    /// doesn't call `parse` and skips the tokens of the syntax into a bogus node of `bogus_kind`
+    /// instead, so that deeply nested input can't overflow the stack. An expression ends before
+    /// the next `,`, `;`, or closing bracket that isn't nested in the skipped tokens. A statement
+    /// ends after its `;` or its block.
+    pub(crate) fn parse_nested(
+        &mut self,
+        bogus_kind: JsSyntaxKind,
+        parse: impl FnOnce(&mut Self) -> ParsedSyntax,
+    ) -> ParsedSyntax {
+        let limit = self.options.nesting_depth_limit();
+
+        if self.state.nesting_depth < limit {
+            self.state.nesting_depth += 1;
+            let syntax = parse(self);
+            self.state.nesting_depth -= 1;
+            return syntax;
+        }
+
+        // Skipping the tokens would make the speculative parsing succeed
+        if self.state.speculative_parsing {
+            return Absent;
+        }
+
+        const OPENING: TokenSet<JsSyntaxKind> = token_set![T!['('], T!['['], T!['{']];
+        const CLOSING: TokenSet<JsSyntaxKind> = token_set![T![')'], T![']'], T!['}']];
+
+        let is_statement = bogus_kind == JsSyntaxKind::JS_BOGUS_STATEMENT;
+        let m = self.start();
+        let mut depth = 0usize;
+        let mut is_empty = true;
+
+        while !self.at(JsSyntaxKind::EOF) {
+            let kind = self.cur();
+
+            if depth == 0
+                && (CLOSING.contains(kind) || (!is_statement && matches!(kind, T![,] | T![;])))
+            {
+                break;
+            }
+
+            if OPENING.contains(kind) {
+                depth += 1;
+            } else if CLOSING.contains(kind) {
+                depth -= 1;
+            }
+
+            self.bump_any();
+            is_empty = false;
+
+            if is_statement && depth == 0 && matches!(kind, T![;] | T!['}']) {
+                break;
+            }
+        }
+
+        if is_empty {
+            m.abandon(self);
+            return Absent;
+        }
+
+        let skipped = m.complete(self, bogus_kind);
+        let diagnostic = self
+            .err_builder("This code is nested too deeply.", skipped.range(self))
+            .with_hint(format!(
+                "The parser skips syntax nested deeper than {limit} levels to avoid a stack overflow."
+            ));
+        self.error(diagnostic);
+        self.max_depth_reached = true;
+
+        Present(skipped)
+    }
+
     pub(crate) fn state(&self) -> &JsParserState {
         &self.state
     }
@@ -64,6 +206,21 @@ impl<'source> JsParser<'source> {
         &mut self.state
     }
 
+    /// Creates a diagnostic for syntax that isn't allowed in strict mode, with a label that
+    /// explains why the code at the current position is in strict mode.
+    pub(crate) fn strict_mode_err_builder(
+        &self,
+        message: impl biome_console::fmt::Display,
+        span: impl AsSpan,
+    ) -> ParseDiagnostic {
+        let diagnostic = self.err_builder(message, span);
+
+        match self.state().strict() {
+            Some(strict) => strict.annotate(diagnostic),
+            None => diagnostic,
+        }
+    }
+
     pub fn source_type(&self) -> JsFileSource {
         self.source_type
     }
@@ -142,9 +299,13 @@ impl<'source> JsParser<'source> {
 
     pub fn finish(self) -> (Vec<Event<JsSyntaxKind>>, Vec<Trivia>, Vec<ParseDiagnostic>) {
         let (trivia, source_diagnostics) = self.source.finish();
+        let (omitted, omitted_range) = self.context.omitted_diagnostics();
         let (events, parse_diagnostics) = self.context.finish();
 
-        let diagnostics = merge_diagnostics(source_diagnostics, parse_diagnostics);
+        let mut diagnostics = merge_diagnostics(source_diagnostics, parse_diagnostics);
+        if let Some(max) = self.options.max_diagnostics {
+            truncate_diagnostics(&mut diagnostics, max, omitted, omitted_range);
+        }
 
         (events, trivia, diagnostics)
     }
@@ -218,8 +379,11 @@ pub struct JsParserCheckpoint {
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
+    use crate::syntax::expr::{parse_expression, ExpressionContext};
     use crate::JsParserOptions;
-    use biome_js_syntax::{JsFileSource, JsSyntaxKind};
+    use biome_diagnostics::Diagnostic;
+    use biome_js_syntax::{JsFileSource, JsSyntaxKind, TextRange, TextSize, T};
+    use biome_parser::parse_recovery::{ParseRecovery, RecoveryError};
 
     #[test]
     #[should_panic(
@@ -249,6 +413,25 @@ mod tests {
         m.complete(&mut p, JsSyntaxKind::JS_STRING_LITERAL_EXPRESSION);
     }
 
+    #[test]
+    fn completed_marker_kind_and_range() {
+        let mut p = JsParser::new(
+            "  a + b;",
+            JsFileSource::default(),
+            JsParserOptions::default(),
+        );
+
+        let expression = parse_expression(&mut p, ExpressionContext::default()).unwrap();
+
+        assert_eq!(expression.kind(&p), JsSyntaxKind::JS_BINARY_EXPRESSION);
+        assert_eq!(
+            expression.range(&p),
+            TextRange::new(TextSize::from(2), TextSize::from(7))
+        );
+        assert_eq!(expression.text(&p), "a + b");
+        assert!(p.at(T![;]));
+    }
+
     #[test]
     fn abandoned_marker_doesnt_panic() {
         let mut p = JsParser::new(
@@ -260,4 +443,97 @@ mod tests {
         let m = p.start();
         m.abandon(&mut p);
     }
+
+    struct StatementRecovery {
+        boundary: TokenSet<JsSyntaxKind>,
+    }
+
+    impl ParseRecovery for StatementRecovery {
+        type Kind = JsSyntaxKind;
+        type Parser<'source> = JsParser<'source>;
+        const RECOVERED_KIND: Self::Kind = JsSyntaxKind::JS_BOGUS_STATEMENT;
+
+        fn is_at_recovered(&self, p: &mut Self::Parser<'_>) -> bool {
+            p.at(T![;])
+        }
+
+        fn recovery_boundary(&self) -> TokenSet<JsSyntaxKind> {
+            self.boundary
+        }
+    }
+
+    #[test]
+    fn recovery_stops_at_boundary() {
+        let source = "a b } c;";
+
+        let mut p = JsParser::new(source, JsFileSource::default(), JsParserOptions::default());
+        let recovery = StatementRecovery {
+            boundary: TokenSet::EMPTY,
+        };
+        assert!(recovery.recover(&mut p).is_ok());
+        assert!(p.at(T![;]));
+
+        let mut p = JsParser::new(source, JsFileSource::default(), JsParserOptions::default());
+        let recovery = StatementRecovery {
+            boundary: token_set![T!['}']],
+        };
+        assert!(recovery.recover(&mut p).is_ok());
+        assert!(p.at(T!['}']));
+        assert_eq!(
+            recovery.recover(&mut p).err(),
+            Some(RecoveryError::AlreadyRecovered)
+        );
+    }
+
+    struct ParenthesizedExpressionRecovery;
+
+    impl ParseRecovery for ParenthesizedExpressionRecovery {
+        type Kind = JsSyntaxKind;
+        type Parser<'source> = JsParser<'source>;
+        const RECOVERED_KIND: Self::Kind = JsSyntaxKind::JS_BOGUS_EXPRESSION;
+
+        fn is_at_recovered(&self, p: &mut Self::Parser<'_>) -> bool {
+            p.at(T![')'])
+        }
+    }
+
+    /// Parses `(expression)` and returns the expression, or the recovered node if it's missing.
+    fn parse_parenthesized(p: &mut JsParser) -> CompletedMarker {
+        p.expect(T!['(']);
+        let expression = parse_expression(p, ExpressionContext::default()).or_recover_with(
+            p,
+            &ParenthesizedExpressionRecovery,
+            |p, range| p.err_builder("Expected an expression", range),
+        );
+        p.expect(T![')']);
+        expression
+    }
+
+    #[test]
+    fn or_recover_with_recovers_missing_expression() {
+        let mut p = JsParser::new("(a)", JsFileSource::default(), JsParserOptions::default());
+        let expression = parse_parenthesized(&mut p);
+        assert_eq!(expression.kind(&p), JsSyntaxKind::JS_IDENTIFIER_EXPRESSION);
+        assert!(p.finish().2.is_empty());
+
+        let mut p = JsParser::new("(; ;)", JsFileSource::default(), JsParserOptions::default());
+        let expression = parse_parenthesized(&mut p);
+        assert_eq!(expression.kind(&p), JsSyntaxKind::JS_BOGUS_EXPRESSION);
+        assert_eq!(expression.text(&p), "; ;");
+        assert!(p.at(JsSyntaxKind::EOF));
+        let diagnostics = p.finish().2;
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].location().span,
+            Some(TextRange::new(TextSize::from(1), TextSize::from(4)))
+        );
+
+        // The recovery fails because the parser is already at the `)`
+        let mut p = JsParser::new("()", JsFileSource::default(), JsParserOptions::default());
+        let expression = parse_parenthesized(&mut p);
+        assert_eq!(expression.kind(&p), JsSyntaxKind::JS_BOGUS_EXPRESSION);
+        assert!(expression.range(&p).is_empty());
+        assert!(p.at(JsSyntaxKind::EOF));
+        assert_eq!(p.finish().2.len(), 1);
+    }
 }
@@ -117,15 +117,41 @@ pub(crate) struct JsParserState {
     /// Stores the token positions of all syntax that looks like an arrow expressions but aren't one.
     /// Optimization to reduce the back-tracking required when parsing parenthesized and arrow function expressions.
     pub(crate) not_parenthesized_arrow: FxHashSet<TextSize>,
+
+    /// The number of statements and expressions the parser is nested in, see [crate::JsParser::parse_nested].
+    pub(crate) nesting_depth: usize,
+
+    /// Whether the parser parsed an `await` expression, a `for await` loop, or an `await using`
+    /// declaration outside of any function, see [crate::Parse::has_top_level_await].
+    pub(crate) has_top_level_await: bool,
 }
 
+/// The reason why code is in strict mode.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) enum StrictMode {
+    /// Modules are always in strict mode.
     Module,
-    Explicit(TextRange),
+    /// A `"use strict"` directive, with the range of its string literal.
+    Directive(TextRange),
+    /// Class bodies are always in strict mode, with the range of the `class` keyword.
     Class(TextRange),
 }
 
+impl StrictMode {
+    /// Adds a label to `diagnostic` that explains why the code is in strict mode.
+    pub(crate) fn annotate(&self, diagnostic: ParseDiagnostic) -> ParseDiagnostic {
+        match self {
+            StrictMode::Module => diagnostic.with_hint("Modules are always in strict mode."),
+            StrictMode::Directive(range) => {
+                diagnostic.with_detail(*range, "Strict mode is enabled by this directive.")
+            }
+            StrictMode::Class(range) => {
+                diagnostic.with_detail(*range, "Class bodies are always in strict mode.")
+            }
+        }
+    }
+}
+
 impl JsParserState {
     pub fn new(source_type: &JsFileSource) -> Self {
         let mut state = JsParserState {
@@ -140,6 +166,8 @@ impl JsParserState {
             duplicate_binding_parent: None,
             not_parenthesized_arrow: Default::default(),
             speculative_parsing: false,
+            nesting_depth: 0,
+            has_top_level_await: false,
         };
 
         if source_type.module_kind().is_module() {
@@ -148,7 +176,7 @@ impl JsParserState {
 
         // test d.ts arguments_in_definition_file
         // function a(...arguments: any[]): void;
-        if source_type.language().is_definition_file() {
+        if source_type.is_definition_file() {
             EnterAmbientContext.apply(&mut state);
         }
 
@@ -184,6 +212,11 @@ impl JsParserState {
             .contains(ParsingContextFlags::TOP_LEVEL)
     }
 
+    pub fn new_target_allowed(&self) -> bool {
+        self.parsing_context
+            .contains(ParsingContextFlags::NEW_TARGET_ALLOWED)
+    }
+
     pub fn continue_allowed(&self) -> bool {
         self.parsing_context
             .contains(ParsingContextFlags::CONTINUE_ALLOWED)
@@ -201,6 +234,14 @@ impl JsParserState {
         self.label_set.get(label)
     }
 
+    /// Records that the parser parsed an `await`, if it's at the top level of a module rather than
+    /// inside a function, a class field initializer, or a class static block.
+    pub(crate) fn record_await(&mut self) {
+        if self.is_top_level() && self.in_async() {
+            self.has_top_level_await = true;
+        }
+    }
+
     pub(super) fn checkpoint(&self) -> JsParserStateCheckpoint {
         JsParserStateCheckpoint::snapshot(self)
     }
@@ -217,6 +258,9 @@ impl JsParserState {
 /// before adding new unscoped state.
 #[derive(Debug)]
 pub(super) struct JsParserStateCheckpoint {
+    /// Top-level awaits parsed after the checkpoint are discarded when rewinding.
+    has_top_level_await: bool,
+
     /// Additional data that we only want to store in debug mode
     #[cfg(debug_assertions)]
     debug_checkpoint: JsDebugParserStateCheckpoint,
@@ -227,23 +271,29 @@ impl JsParserStateCheckpoint {
     #[cfg(debug_assertions)]
     fn snapshot(state: &JsParserState) -> Self {
         Self {
+            has_top_level_await: state.has_top_level_await,
             debug_checkpoint: JsDebugParserStateCheckpoint::snapshot(state),
         }
     }
 
     #[cfg(not(debug_assertions))]
-    fn snapshot(_: &JsParserState) -> Self {
-        Self {}
+    fn snapshot(state: &JsParserState) -> Self {
+        Self {
+            has_top_level_await: state.has_top_level_await,
+        }
     }
 
     /// Restores the `state values` to the time when this snapshot was created.
     #[cfg(debug_assertions)]
     fn rewind(self, state: &mut JsParserState) {
+        state.has_top_level_await = self.has_top_level_await;
         self.debug_checkpoint.rewind(state);
     }
 
     #[cfg(not(debug_assertions))]
-    fn rewind(self, _: &JsParserState) {}
+    fn rewind(self, state: &mut JsParserState) {
+        state.has_top_level_await = self.has_top_level_await;
+    }
 }
 
 /// Most of the [JsParserState] is scoped state. It should, therefore, not be necessary to rewind
@@ -370,6 +420,8 @@ bitflags! {
         const GENERATOR 	= 1 << 1;
         /// Is the function a constructor (or constructor context)
         const CONSTRUCTOR 	= 1 << 2;
+        /// Is the function an arrow function, which doesn't bind `new.target`
+        const ARROW 		= 1 << 3;
     }
 }
 
@@ -389,6 +441,11 @@ impl From<SignatureFlags> for ParsingContextFlags {
             parsing_context |= ParsingContextFlags::IN_CONSTRUCTOR;
         }
 
+        // Arrow functions inherit `new.target` from the enclosing scope
+        if !flags.contains(SignatureFlags::ARROW) {
+            parsing_context |= ParsingContextFlags::NEW_TARGET_ALLOWED;
+        }
+
         parsing_context
     }
 }
@@ -402,7 +459,7 @@ bitflags! {
     ///   snapshots each individual boolean field to allow restoring the previous state. With bitflags, all that
     ///   is needed is to copy away the flags field and restore it after.
     #[derive(Debug, Copy, Default, Clone, Eq, PartialEq)]
-    pub(crate) struct ParsingContextFlags: u8 {
+    pub(crate) struct ParsingContextFlags: u16 {
         /// Whether the parser is in a generator function like `function* a() {}`
         /// Matches the `Yield` parameter in the ECMA spec
         const IN_GENERATOR = 1 << 0;
@@ -428,6 +485,10 @@ bitflags! {
         /// Whatever the parser is in a TypeScript ambient context
         const AMBIENT_CONTEXT = 1 << 7;
 
+        /// Whether `new.target` is allowed because the parser is inside a function that isn't an arrow
+        /// function, a class field initializer, or a class static block
+        const NEW_TARGET_ALLOWED = 1 << 8;
+
         const LOOP = Self::BREAK_ALLOWED.bits() | Self::CONTINUE_ALLOWED.bits();
 
         /// Bitmask of all the flags that must be reset (shouldn't be inherited) when the parser enters a function
@@ -529,10 +590,11 @@ pub(crate) struct EnterClassPropertyInitializer;
 
 impl ChangeParserStateFlags for EnterClassPropertyInitializer {
     fn compute_new_flags(&self, existing: ParsingContextFlags) -> ParsingContextFlags {
-        existing
+        (existing
             - ParsingContextFlags::TOP_LEVEL
             - ParsingContextFlags::IN_ASYNC
-            - ParsingContextFlags::IN_GENERATOR
+            - ParsingContextFlags::IN_GENERATOR)
+            | ParsingContextFlags::NEW_TARGET_ALLOWED
     }
 }
 
@@ -551,7 +613,8 @@ impl ChangeParserState for EnterClassStaticInitializationBlock {
         let flags = (state.parsing_context
             - ParsingContextFlags::FUNCTION_RESET_MASK
             - ParsingContextFlags::IN_FUNCTION)
-            | ParsingContextFlags::IN_ASYNC; // allow async for better error recovery
+            | ParsingContextFlags::IN_ASYNC // allow async for better error recovery
+            | ParsingContextFlags::NEW_TARGET_ALLOWED;
         EnterClassStaticInitializationBlockSnapshot {
             flags: std::mem::replace(&mut state.parsing_context, flags),
             label_set: std::mem::take(&mut state.label_set),
@@ -602,6 +665,16 @@ impl ChangeParserState for WithLabel {
     }
 }
 
+/// Allows `new.target` without reporting it, for callers that report a more specific diagnostic
+/// for the `new.target` expression.
+pub(crate) struct AllowNewTarget;
+
+impl ChangeParserStateFlags for AllowNewTarget {
+    fn compute_new_flags(&self, existing: ParsingContextFlags) -> ParsingContextFlags {
+        existing | ParsingContextFlags::NEW_TARGET_ALLOWED
+    }
+}
+
 /// Sets the state changes needed when parsing a TS type declaration (async and await are not reserved identifiers)
 pub(crate) struct EnterType;
 
@@ -14,9 +14,10 @@ use crate::syntax::function::{
 };
 use crate::syntax::js_parse_error;
 use crate::syntax::js_parse_error::{
-    decorator_must_precede_modifier, decorators_not_allowed, expected_binding, expected_expression,
-    invalid_decorator_error, modifier_already_seen, modifier_cannot_be_used_with_modifier,
-    modifier_must_precede_modifier, parameter_decorators_not_allowed,
+    accessor_modifier_not_supported, decorator_must_precede_modifier, decorators_not_allowed,
+    decorators_not_supported, expected_binding, expected_expression, invalid_decorator_error,
+    modifier_already_seen, modifier_cannot_be_used_with_modifier, modifier_must_precede_modifier,
+    parameter_decorators_not_allowed, unsupported_version_syntax_error,
 };
 use crate::syntax::object::{
     is_at_literal_member_name, parse_computed_member_name, parse_literal_member_name,
@@ -33,12 +34,12 @@ use crate::syntax::typescript::{
     parse_ts_type_annotation, parse_ts_type_arguments, parse_ts_type_parameters, TypeContext,
 };
 
-use crate::JsSyntaxFeature::TypeScript;
+use crate::JsSyntaxFeature::{Decorators, MinimumVersion, TypeAnnotations, TypeScript};
 use crate::ParsedSyntax::{Absent, Present};
 use crate::{JsParser, StrictMode};
 use biome_js_syntax::JsSyntaxKind::*;
 use biome_js_syntax::TextSize;
-use biome_js_syntax::{JsSyntaxKind, T};
+use biome_js_syntax::{EcmaFeature, JsSyntaxKind, LanguageVersion, T};
 use biome_parser::parse_lists::ParseNodeList;
 use biome_parser::parse_recovery::ParseRecoveryTokenSet;
 use biome_parser::ParserProgress;
@@ -235,7 +236,7 @@ fn parse_class(p: &mut JsParser, kind: ClassKind, decorator_list: ParsedSyntax)
     let class_token_range = p.cur_range();
     p.expect(T![class]);
 
-    let p = &mut *p.with_scoped_state(EnableStrictMode(StrictMode::Class(p.cur_range())));
+    let p = &mut *p.with_scoped_state(EnableStrictMode(StrictMode::Class(class_token_range)));
 
     // test_err ts class_decl_no_id
     // class {}
@@ -277,7 +278,7 @@ fn parse_class(p: &mut JsParser, kind: ClassKind, decorator_list: ParsedSyntax)
 
     // test_err ts ts_class_type_parameters_errors
     // class BuildError<> {}
-    TypeScript
+    TypeAnnotations
         .parse_exclusive_syntax(
             p,
             |p| {
@@ -528,11 +529,16 @@ fn parse_class_member(p: &mut JsParser, inside_abstract_class: bool) -> ParsedSy
     let mut modifiers = parse_class_member_modifiers(p, false);
 
     if is_at_static_initialization_block_class_member(p) {
-        return Present(parse_static_initialization_block_class_member(
-            p,
-            member_marker,
-            modifiers,
-        ));
+        let member = parse_static_initialization_block_class_member(p, member_marker, modifiers);
+
+        return MinimumVersion(LanguageVersion::ES2022).exclusive_syntax(p, member, |p, member| {
+            unsupported_version_syntax_error(
+                p,
+                "Class static blocks",
+                LanguageVersion::ES2022,
+                member.range(p),
+            )
+        });
     }
 
     let member = parse_class_member_impl(p, member_marker, &mut modifiers);
@@ -566,6 +572,18 @@ fn parse_class_member(p: &mut JsParser, inside_abstract_class: bool) -> ParsedSy
                 member.change_to_bogus(p);
             }
 
+            let class_fields = EcmaFeature::ClassFields.minimum_version();
+            if member.kind(p) == JS_PROPERTY_CLASS_MEMBER
+                && MinimumVersion(class_fields).is_unsupported(p)
+            {
+                p.error(unsupported_version_syntax_error(
+                    p,
+                    "Class fields",
+                    class_fields,
+                    member.range(p),
+                ));
+            }
+
             Present(member)
         }
         Absent => {
@@ -1248,7 +1266,7 @@ fn parse_method_class_member_rest(
     // class A { test?() {} }
     let optional = optional_member_token(p);
 
-    TypeScript
+    TypeAnnotations
         .parse_exclusive_syntax(
             p,
             |p| parse_ts_type_parameters(p, TypeContext::default().and_allow_const_modifier(true)),
@@ -1267,7 +1285,7 @@ fn parse_method_class_member_rest(
     parse_parameter_list(p, parameter_context, TypeContext::default(), flags)
         .or_add_diagnostic(p, js_parse_error::expected_class_parameters);
 
-    TypeScript
+    TypeAnnotations
         .parse_exclusive_syntax(
             p,
             |p| parse_ts_return_type_annotation(p, TypeContext::default()),
@@ -2581,7 +2599,9 @@ impl ClassMemberModifiers {
                 }
             }
             ModifierKind::Accessor => {
-                if preceding_modifiers.contains(ModifierFlags::ACCESSOR) {
+                if Decorators.is_unsupported(p) {
+                    return Some(accessor_modifier_not_supported(p, modifier.as_text_range()));
+                } else if preceding_modifiers.contains(ModifierFlags::ACCESSOR) {
                     return Some(modifier_already_seen(
                         p,
                         modifier.as_text_range(),
@@ -2710,7 +2730,11 @@ pub(crate) fn parse_decorators(p: &mut JsParser) -> ParsedSyntax {
         parse_decorator(p).ok();
     }
 
-    Present(decorators.complete(p, JS_DECORATOR_LIST))
+    let decorators = decorators.complete(p, JS_DECORATOR_LIST);
+
+    Decorators.exclusive_syntax(p, decorators, |p, decorators| {
+        decorators_not_supported(p, decorators.range(p))
+    })
 }
 
 pub(crate) fn parse_parameter_decorators(p: &mut JsParser) -> ParsedSyntax {
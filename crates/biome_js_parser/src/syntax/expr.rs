@@ -21,15 +21,17 @@ use crate::syntax::js_parse_error;
 use crate::syntax::js_parse_error::{decorators_not_allowed, expected_simple_assignment_target};
 use crate::syntax::js_parse_error::{
     expected_expression, expected_identifier, invalid_assignment_error,
-    private_names_only_allowed_on_left_side_of_in_expression,
+    private_names_only_allowed_on_left_side_of_in_expression, unsupported_version_syntax_error,
 };
 use crate::syntax::jsx::parse_jsx_tag_expression;
 use crate::syntax::object::parse_object_expression;
 use crate::syntax::stmt::{is_semi, STMT_RECOVERY_SET};
 use crate::syntax::typescript::ts_parse_error::{expected_ts_type, ts_only_syntax_error};
-use crate::JsSyntaxFeature::{Jsx, StrictMode, TypeScript};
+use crate::JsSyntaxFeature::{Jsx, MinimumVersion, StrictMode, TypeScript};
 use crate::ParsedSyntax::{Absent, Present};
 use crate::{syntax, JsParser, ParseRecoveryTokenSet, ParsedSyntax};
+use biome_diagnostics::Severity;
+use biome_js_syntax::numbers::exceeds_max_safe_integer;
 use biome_js_syntax::{JsSyntaxKind::*, *};
 use biome_parser::diagnostic::expected_token;
 use biome_parser::parse_lists::ParseSeparatedList;
@@ -173,7 +175,12 @@ pub(super) fn parse_literal_expression(p: &mut JsParser) -> ParsedSyntax {
             return parse_number_literal_expression(p)
                 .or_else(|| parse_big_int_literal_expression(p));
         }
-        JsSyntaxKind::JS_STRING_LITERAL => JsSyntaxKind::JS_STRING_LITERAL_EXPRESSION,
+        JsSyntaxKind::JS_STRING_LITERAL => {
+            if p.state().strict().is_some() {
+                report_non_octal_decimal_escapes(p);
+            }
+            JsSyntaxKind::JS_STRING_LITERAL_EXPRESSION
+        }
         JsSyntaxKind::NULL_KW => JsSyntaxKind::JS_NULL_LITERAL_EXPRESSION,
         JsSyntaxKind::TRUE_KW | JsSyntaxKind::FALSE_KW => {
             JsSyntaxKind::JS_BOOLEAN_LITERAL_EXPRESSION
@@ -193,6 +200,40 @@ pub(super) fn parse_literal_expression(p: &mut JsParser) -> ParsedSyntax {
     Present(m.complete(p, literal_kind))
 }
 
+/// Reports the `\8` and `\9` escape sequences of the current string literal, which are
+/// forbidden in strict mode.
+fn report_non_octal_decimal_escapes(p: &mut JsParser) {
+    let start = p.cur_range().start();
+    let bytes = p.cur_text().as_bytes();
+    let mut escapes = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if bytes[index] == b'\\' {
+            if matches!(bytes.get(index + 1), Some(b'8' | b'9')) {
+                escapes.push(TextRange::at(
+                    start + TextSize::from(index as u32),
+                    TextSize::from(2),
+                ));
+            }
+            // Skip the escaped character, which may be a backslash
+            index += 2;
+        } else {
+            index += 1;
+        }
+    }
+
+    for range in escapes {
+        p.error(
+            p.strict_mode_err_builder(
+                "The escape sequences \\8 and \\9 are not allowed in strict mode.",
+                range,
+            )
+            .with_hint("Use the digit without a backslash instead."),
+        );
+    }
+}
+
 pub(crate) fn parse_big_int_literal_expression(p: &mut JsParser) -> ParsedSyntax {
     if !p.at(JS_NUMBER_LITERAL) || !p.cur_text().ends_with('n') {
         return Absent;
@@ -224,6 +265,15 @@ pub(crate) fn parse_number_literal_expression(p: &mut JsParser) -> ParsedSyntax
             "\"0\"-prefixed octal literals are deprecated; use the \"0o\" prefix instead."
         };
         p.error(p.err_builder(err_msg, p.cur_range()));
+    } else if p.options().check_unsafe_integers && exceeds_max_safe_integer(cur_src) {
+        p.error(
+            p.err_builder(
+                "This number literal exceeds `Number.MAX_SAFE_INTEGER` and loses precision.",
+                p.cur_range(),
+            )
+            .with_hint("Use a BigInt literal by appending `n` to keep the exact value.")
+            .with_severity(Severity::Warning),
+        );
     }
 
     let m = p.start();
@@ -237,13 +287,15 @@ pub(crate) fn parse_assignment_expression_or_higher(
     p: &mut JsParser,
     context: ExpressionContext,
 ) -> ParsedSyntax {
-    let arrow_expression = parse_arrow_function_expression(p);
+    p.parse_nested(JS_BOGUS_EXPRESSION, |p| {
+        let arrow_expression = parse_arrow_function_expression(p);
 
-    if arrow_expression.is_present() {
-        return arrow_expression;
-    }
+        if arrow_expression.is_present() {
+            return arrow_expression;
+        }
 
-    parse_assignment_expression_or_higher_base(p, context)
+        parse_assignment_expression_or_higher_base(p, context)
+    })
 }
 
 fn parse_assignment_expression_or_higher_base(
@@ -316,8 +368,21 @@ fn parse_assign_expr_recursive(
         };
 
         let m = target.precede(p);
+        let operator_range = p.cur_range();
         p.expect(assign_operator);
 
+        let logical_assignment = EcmaFeature::LogicalAssignment.minimum_version();
+        if matches!(assign_operator, T![&&=] | T![||=] | T![??=])
+            && MinimumVersion(logical_assignment).is_unsupported(p)
+        {
+            p.error(unsupported_version_syntax_error(
+                p,
+                "Logical assignment operators",
+                logical_assignment,
+                operator_range,
+            ));
+        }
+
         parse_assignment_expression_or_higher(p, context.and_object_expression_allowed(true))
             .or_add_diagnostic(p, js_parse_error::expected_expression_assignment);
         Present(m.complete(p, JS_ASSIGNMENT_EXPRESSION))
@@ -615,6 +680,16 @@ fn parse_binary_or_logical_expression_recursive(
         let m = left.precede(p);
         p.bump(op);
 
+        let nullish_coalescing = EcmaFeature::NullishCoalescing.minimum_version();
+        if op == T![??] && MinimumVersion(nullish_coalescing).is_unsupported(p) {
+            p.error(unsupported_version_syntax_error(
+                p,
+                "Nullish coalescing operators",
+                nullish_coalescing,
+                op_range,
+            ));
+        }
+
         // test ts ts_as_expression
         // let x: any = "string";
         // let y = x as string;
@@ -723,7 +798,7 @@ fn parse_binary_or_logical_expression_recursive(
 // test js new_exprs
 // new Foo()
 // new foo;
-// new.target
+// function f() { new.target }
 // new new new new Foo();
 // new Foo(bar, baz, 6 + 6, foo[bar] + ((foo) => {}) * foo?.bar)
 
@@ -756,6 +831,7 @@ fn parse_member_expression_rest(
                 parse_computed_member_expression(p, lhs, false).unwrap()
             }
             T![?.] if allow_optional_chain => {
+                let optional_chain_range = p.cur_range();
                 let completed = if p.nth_at(1, T!['[']) {
                     parse_computed_member_expression(p, lhs, true).unwrap()
                 } else if is_nth_at_any_name(p, 1) {
@@ -770,6 +846,7 @@ fn parse_member_expression_rest(
                     // '(' or any other unexpected character
                     break;
                 };
+                report_unsupported_optional_chain(p, optional_chain_range);
                 *in_optional_chain = true;
                 completed
             }
@@ -843,7 +920,15 @@ fn parse_new_expr(p: &mut JsParser, context: ExpressionContext) -> ParsedSyntax
     // new.target
     if p.eat(T![.]) {
         if p.at(T![ident]) && p.cur_text() == "target" {
+            let range = TextRange::new(m.start(), p.cur_range().end());
             p.bump_remap(TARGET);
+
+            // test_err js new_target_outside_function
+            // new.target;
+            // () => new.target;
+            if !p.state().new_target_allowed() {
+                p.error(p.err_builder("`new.target` is only valid inside of a function.", range));
+            }
         } else if is_at_identifier(p) {
             let identifier_range = p.cur_range();
             let name = p.cur_text();
@@ -1037,6 +1122,19 @@ pub(super) fn parse_any_name(p: &mut JsParser) -> ParsedSyntax {
     }
 }
 
+/// Reports the `?.` token at `range` if the configured language version doesn't support optional chains.
+fn report_unsupported_optional_chain(p: &mut JsParser, range: TextRange) {
+    let optional_chaining = EcmaFeature::OptionalChaining.minimum_version();
+    if MinimumVersion(optional_chaining).is_unsupported(p) {
+        p.error(unsupported_version_syntax_error(
+            p,
+            "Optional chains",
+            optional_chaining,
+            range,
+        ));
+    }
+}
+
 /// An array expression for property access or indexing, such as `foo[0]` or `foo?.["bar"]`
 // test js computed_member_expression
 // foo[bar]
@@ -1379,7 +1477,16 @@ fn parse_primary_expression(p: &mut JsParser, context: ExpressionContext) -> Par
                 // import.metaa
                 if p.at(T![ident]) && p.text(p.cur_range()) == "meta" {
                     p.bump_remap(META);
-                    m.complete(p, JS_IMPORT_META_EXPRESSION)
+                    let import_meta = m.complete(p, JS_IMPORT_META_EXPRESSION);
+
+                    if !p.source_type().is_module() {
+                        p.error(p.err_builder(
+                            "`import.meta` is only valid inside of a module.",
+                            import_meta.range(p),
+                        ));
+                    }
+
+                    import_meta
                 } else if p.at(T![ident]) {
                     let err = p.err_builder(
                         format!(
@@ -1834,7 +1941,11 @@ fn parse_call_expression_rest(
         };
 
         let start_pos = p.source().position();
+        let optional_chain_range = p.cur_range();
         let optional_chain_call = p.eat(T![?.]);
+        if optional_chain_call {
+            report_unsupported_optional_chain(p, optional_chain_range);
+        }
         in_optional_chain = in_optional_chain || optional_chain_call;
 
         // test ts ts_call_expr_with_type_arguments
@@ -1927,6 +2038,10 @@ fn parse_postfix_expr(p: &mut JsParser, context: ExpressionContext) -> ParsedSyn
 
 /// A unary expression such as `!foo` or `++bar`
 pub(super) fn parse_unary_expr(p: &mut JsParser, context: ExpressionContext) -> ParsedSyntax {
+    p.parse_nested(JS_BOGUS_EXPRESSION, |p| parse_unary_expr_base(p, context))
+}
+
+fn parse_unary_expr_base(p: &mut JsParser, context: ExpressionContext) -> ParsedSyntax {
     const UNARY_SINGLE: TokenSet<JsSyntaxKind> =
         token_set![T![delete], T![void], T![typeof], T![+], T![-], T![~], T![!]];
 
@@ -1991,6 +2106,19 @@ pub(super) fn parse_unary_expr(p: &mut JsParser, context: ExpressionContext) ->
 
         unary.or_add_diagnostic(p, js_parse_error::expected_unary_expression);
         let expr = m.complete(p, JS_AWAIT_EXPRESSION);
+        p.state_mut().record_await();
+
+        if p.state().is_top_level() {
+            return MinimumVersion(LanguageVersion::ES2022).exclusive_syntax(p, expr, |p, expr| {
+                unsupported_version_syntax_error(
+                    p,
+                    "Top-level await expressions",
+                    LanguageVersion::ES2022,
+                    expr.range(p),
+                )
+            });
+        }
+
         return Present(expr);
     }
 
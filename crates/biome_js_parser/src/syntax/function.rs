@@ -21,7 +21,7 @@ use crate::syntax::typescript::{
     parse_ts_type_parameters, try_parse, TypeContext,
 };
 
-use crate::JsSyntaxFeature::TypeScript;
+use crate::JsSyntaxFeature::{TypeAnnotations, TypeScript};
 use crate::ParsedSyntax::{Absent, Present};
 use crate::{JsParser, JsSyntaxFeature, ParseRecoveryTokenSet};
 use biome_js_syntax::JsSyntaxKind::*;
@@ -232,7 +232,7 @@ fn parse_function(p: &mut JsParser, m: Marker, kind: FunctionKind) -> CompletedM
         });
     }
 
-    TypeScript
+    TypeAnnotations
         .parse_exclusive_syntax(
             p,
             |p| parse_ts_type_parameters(p, TypeContext::default().and_allow_const_modifier(true)),
@@ -258,7 +258,7 @@ fn parse_function(p: &mut JsParser, m: Marker, kind: FunctionKind) -> CompletedM
     parse_parameter_list(p, parameter_context, TypeContext::default(), flags)
         .or_add_diagnostic(p, js_parse_error::expected_parameters);
 
-    TypeScript
+    TypeAnnotations
         .parse_exclusive_syntax(
             p,
             |p| parse_ts_return_type_annotation(p, TypeContext::default()),
@@ -469,7 +469,7 @@ fn parse_ambient_function(
 }
 
 pub(crate) fn parse_ts_type_annotation_or_error(p: &mut JsParser) -> ParsedSyntax {
-    TypeScript.parse_exclusive_syntax(
+    TypeAnnotations.parse_exclusive_syntax(
         p,
         |p| parse_ts_type_annotation(p, TypeContext::default()),
         |p, annotation| {
@@ -584,7 +584,7 @@ fn try_parse_parenthesized_arrow_function_head(
         return Err(m);
     }
 
-    TypeScript
+    TypeAnnotations
         .parse_exclusive_syntax(
             p,
             |p| parse_ts_return_type_annotation(p, TypeContext::default()),
@@ -817,6 +817,8 @@ fn is_parenthesized_arrow_function_expression_impl(
 /// Computes the signature flags for parsing the parameters of an arrow expression. These
 /// have different semantics from parsing the body
 fn arrow_function_parameter_flags(p: &JsParser, mut flags: SignatureFlags) -> SignatureFlags {
+    flags |= SignatureFlags::ARROW;
+
     if p.state().in_generator() {
         // Arrow functions inherit whatever yield is a valid identifier name from the parent.
         flags |= SignatureFlags::GENERATOR;
@@ -895,6 +897,11 @@ fn parse_arrow_body(p: &mut JsParser, mut flags: SignatureFlags) -> ParsedSyntax
         flags |= SignatureFlags::CONSTRUCTOR
     }
 
+    // test js arrow_new_target
+    // function f() { () => new.target; }
+    // class A { a = () => new.target; }
+    flags |= SignatureFlags::ARROW;
+
     if p.at(T!['{']) {
         parse_function_body(p, flags)
     } else {
@@ -987,7 +994,7 @@ pub(crate) fn parse_rest_parameter(
     }
 
     // type annotation `...foo: number[]`
-    TypeScript
+    TypeAnnotations
         .parse_exclusive_syntax(
             p,
             |p| parse_ts_type_annotation(p, type_context),
@@ -1143,7 +1150,7 @@ pub(crate) fn parse_formal_parameter(
         let mut valid = true;
 
         let is_optional = if p.at(T![?]) {
-            if TypeScript.is_unsupported(p) {
+            if TypeAnnotations.is_unsupported(p) {
                 p.error(ts_only_syntax_error(
                     p,
                     "optional parameters",
@@ -1184,7 +1191,7 @@ pub(crate) fn parse_formal_parameter(
             ));
         }
 
-        TypeScript
+        TypeAnnotations
             .parse_exclusive_syntax(
                 p,
                 |p| parse_ts_type_annotation(p, type_context),
@@ -1232,9 +1239,9 @@ pub(super) fn skip_parameter_start(p: &mut JsParser) -> bool {
 
     if p.at(T!['[']) || p.at(T!['{']) {
         // Array or object pattern. Try to parse it and return true if there were no parsing errors
-        let previous_error_count = p.context().diagnostics().len();
+        let previous_error_count = p.context().diagnostics_count();
         let pattern = parse_binding_pattern(p, ExpressionContext::default());
-        pattern.is_present() && p.context().diagnostics().len() == previous_error_count
+        pattern.is_present() && p.context().diagnostics_count() == previous_error_count
     } else {
         false
     }
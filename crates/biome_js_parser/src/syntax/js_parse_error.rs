@@ -4,7 +4,7 @@ use crate::prelude::*;
 use crate::span::Span;
 use crate::JsParser;
 use crate::JsSyntaxFeature::TypeScript;
-use biome_js_syntax::TextRange;
+use biome_js_syntax::{LanguageVersion, TextRange};
 use biome_parser::diagnostic::{expected_any, expected_node};
 
 pub(crate) fn expected_function_body(p: &JsParser, range: TextRange) -> ParseDiagnostic {
@@ -304,9 +304,49 @@ pub(crate) fn decorators_not_allowed(p: &JsParser, range: TextRange) -> ParseDia
     )
 }
 
+pub(crate) fn decorators_not_supported(p: &JsParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder(
+        format!(
+            "Decorators require TypeScript or ESNext, but the configured version is {:?}.",
+            p.source_type().version()
+        ),
+        range,
+    )
+    .with_hint("Convert your file to a TypeScript file, target ESNext, or remove the decorators.")
+}
+
+pub(crate) fn accessor_modifier_not_supported(p: &JsParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder(
+        format!(
+            "The 'accessor' modifier requires TypeScript or ESNext, but the configured version is {:?}.",
+            p.source_type().version()
+        ),
+        range,
+    )
+    .with_hint("Convert your file to a TypeScript file, target ESNext, or remove the modifier.")
+}
+
 pub(crate) fn decorator_must_precede_modifier(p: &JsParser, range: TextRange) -> ParseDiagnostic {
     p.err_builder(
         "Decorators must precede the name and all keywords of property declarations.",
         range,
     )
 }
+
+pub(crate) fn unsupported_version_syntax_error(
+    p: &JsParser,
+    syntax: &str,
+    required: LanguageVersion,
+    range: TextRange,
+) -> ParseDiagnostic {
+    p.err_builder(
+        format!(
+            "{syntax} require {required:?} or later, but the configured version is {:?}.",
+            p.source_type().version()
+        ),
+        range,
+    )
+    .with_hint(format!(
+        "Target {required:?} or later, or remove the syntax."
+    ))
+}
@@ -7,6 +7,7 @@ use biome_parser::parse_lists::ParseNodeList;
 use biome_rowan::TextRange;
 
 use crate::lexer::{JsLexContext, JsReLexContext, JsSyntaxKind, T};
+use crate::state::AllowNewTarget;
 use crate::syntax::expr::{
     is_nth_at_identifier_or_keyword, parse_expression, parse_name, ExpressionContext,
 };
@@ -695,7 +696,18 @@ fn parse_jsx_expression_attribute_value(p: &mut JsParser) -> ParsedSyntax {
 //   {new.target}
 // </div>
 fn parse_jsx_assignment_expression(p: &mut JsParser, is_spread: bool) -> ParsedSyntax {
-    let expr = parse_expression(p, ExpressionContext::default());
+    // A `new.target` child is reported as an invalid JSX expression below, which takes precedence
+    // over reporting that it's outside of a function.
+    let is_new_target_child =
+        p.at(T![new]) && p.nth_at(1, T![.]) && p.nth_at(2, T![ident]) && p.nth_at(3, T!['}']);
+
+    let expr = if is_new_target_child {
+        p.with_state(AllowNewTarget, |p| {
+            parse_expression(p, ExpressionContext::default())
+        })
+    } else {
+        parse_expression(p, ExpressionContext::default())
+    };
 
     expr.map(|mut expr| {
         let msg = if is_spread {
@@ -21,6 +21,7 @@ use crate::syntax::js_parse_error::{
     expected_export_clause, expected_export_default_declaration, expected_export_name_specifier,
     expected_expression, expected_identifier, expected_literal_export_name, expected_module_source,
     expected_named_import_specifier, expected_namespace_or_named_import, expected_statement,
+    unsupported_version_syntax_error,
 };
 use crate::syntax::stmt::{parse_statement, semi, StatementContext, STMT_RECOVERY_SET};
 use crate::syntax::typescript::ts_parse_error::ts_only_syntax_error;
@@ -28,10 +29,10 @@ use crate::syntax::typescript::{
     parse_ts_enum_declaration, parse_ts_import_equals_declaration_rest,
     parse_ts_interface_declaration,
 };
-use crate::JsSyntaxFeature::TypeScript;
+use crate::JsSyntaxFeature::{MinimumVersion, TypeScript};
 use crate::{Absent, JsParser, ParseRecoveryTokenSet, ParsedSyntax, Present};
 use biome_js_syntax::JsSyntaxKind::*;
-use biome_js_syntax::{JsSyntaxKind, TextRange, T};
+use biome_js_syntax::{JsSyntaxKind, LanguageVersion, TextRange, T};
 use biome_parser::diagnostic::{expected_any, expected_node};
 use biome_parser::parse_lists::ParseSeparatedList;
 use biome_parser::parse_recovery::RecoveryResult;
@@ -105,7 +106,7 @@ pub(crate) fn parse_module_item_list(
             expected_statement,
         );
 
-        if recovered.is_err() {
+        if recovered.is_err() || p.should_stop() {
             break;
         }
     }
@@ -1051,7 +1052,16 @@ fn parse_export_from_clause(p: &mut JsParser) -> ParsedSyntax {
     p.eat(T![type]);
     p.expect(T![*]);
 
-    parse_export_as_clause(p).ok();
+    if let Present(as_clause) = parse_export_as_clause(p) {
+        if MinimumVersion(LanguageVersion::ES2020).is_unsupported(p) {
+            p.error(unsupported_version_syntax_error(
+                p,
+                "Namespace re-exports",
+                LanguageVersion::ES2020,
+                as_clause.range(p),
+            ));
+        }
+    }
     p.expect(T![from]);
     parse_module_source(p).or_add_diagnostic(p, expected_module_source);
     parse_import_assertion(p).ok();
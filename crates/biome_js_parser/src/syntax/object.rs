@@ -21,7 +21,7 @@ use crate::syntax::typescript::{
     parse_ts_return_type_annotation, parse_ts_type_annotation, parse_ts_type_parameters,
     TypeContext,
 };
-use crate::JsSyntaxFeature::TypeScript;
+use crate::JsSyntaxFeature::TypeAnnotations;
 use crate::{JsParser, ParseRecoveryTokenSet};
 use biome_js_syntax::JsSyntaxKind::*;
 use biome_js_syntax::{JsSyntaxKind, T};
@@ -269,7 +269,7 @@ fn parse_getter_object_member(p: &mut JsParser) -> ParsedSyntax {
     p.expect(T!['(']);
     p.expect(T![')']);
 
-    TypeScript
+    TypeAnnotations
         .parse_exclusive_syntax(
             p,
             |p| parse_ts_type_annotation(p, TypeContext::default()),
@@ -465,7 +465,7 @@ fn parse_method_object_member(p: &mut JsParser) -> ParsedSyntax {
 
 /// Parses the body of a method object member starting right after the member name.
 fn parse_method_object_member_body(p: &mut JsParser, flags: SignatureFlags) {
-    TypeScript
+    TypeAnnotations
         .parse_exclusive_syntax(
             p,
             |p| parse_ts_type_parameters(p, TypeContext::default().and_allow_const_modifier(true)),
@@ -483,7 +483,7 @@ fn parse_method_object_member_body(p: &mut JsParser, flags: SignatureFlags) {
     )
     .or_add_diagnostic(p, js_parse_error::expected_parameters);
 
-    TypeScript
+    TypeAnnotations
         .parse_exclusive_syntax(
             p,
             |p| parse_ts_return_type_annotation(p, TypeContext::default()),
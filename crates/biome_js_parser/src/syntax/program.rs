@@ -4,10 +4,15 @@ use super::module::parse_module_body;
 use super::stmt::parse_statements;
 use crate::prelude::*;
 use crate::state::{ChangeParserState, EnableStrictMode};
+use crate::syntax::expr::{parse_expression, ExpressionContext};
+use crate::syntax::js_parse_error::expected_expression;
 use crate::syntax::stmt::parse_directives;
+use crate::syntax::typescript::ts_parse_error::{expected_ts_type, ts_only_syntax_error};
+use crate::syntax::typescript::{parse_ts_type, TypeContext};
 use crate::JsParser;
+use crate::JsSyntaxFeature::TypeAnnotations;
 use biome_js_syntax::JsSyntaxKind::*;
-use biome_js_syntax::ModuleKind;
+use biome_js_syntax::{ModuleKind, TextRange};
 
 // test_err js unterminated_unicode_codepoint
 // let s = "\u{200";
@@ -15,6 +20,16 @@ use biome_js_syntax::ModuleKind;
 pub(crate) fn parse(p: &mut JsParser) -> CompletedMarker {
     let m = p.start();
     p.eat(UNICODE_BOM);
+
+    if p.at(JS_SHEBANG) && !p.source_type().is_executable() {
+        let err = p
+            .err_builder(
+                "Hashbangs are only allowed in executable files.",
+                p.cur_range(),
+            )
+            .with_hint("TypeScript definition files are never executed, remove the hashbang.");
+        p.error(err);
+    }
     p.eat(JS_SHEBANG);
 
     let (statement_list, strict_snapshot) = parse_directives(p);
@@ -24,7 +39,7 @@ pub(crate) fn parse(p: &mut JsParser) -> CompletedMarker {
             parse_statements(p, false, statement_list);
             m.complete(p, JS_SCRIPT)
         }
-        ModuleKind::Module => {
+        ModuleKind::Module | ModuleKind::Unknown => {
             parse_module_body(p, statement_list);
             m.complete(p, JS_MODULE)
         }
@@ -36,3 +51,67 @@ pub(crate) fn parse(p: &mut JsParser) -> CompletedMarker {
 
     result
 }
+
+/// Parses the source as a single expression, see [crate::parse_expression].
+pub(crate) fn parse_expression_snippet(p: &mut JsParser) -> CompletedMarker {
+    let m = p.start();
+    let expression = parse_expression(p, ExpressionContext::default());
+    let has_expression = expression.is_present();
+
+    if !p.at(EOF) {
+        let bogus = expression.precede(p);
+        let start = p.cur_range().start();
+
+        while !p.at(EOF) {
+            p.bump_any();
+        }
+
+        let range = TextRange::new(start, p.cur_range().start());
+        let diagnostic = if has_expression {
+            p.err_builder("Expected the end of the expression.", range)
+                .with_hint("Only a single expression can be parsed.")
+        } else {
+            expected_expression(p, range)
+        };
+        p.error(diagnostic);
+        bogus.complete(p, JS_BOGUS_EXPRESSION);
+    } else if !has_expression {
+        p.error(expected_expression(p, p.cur_range()));
+    }
+
+    m.complete(p, JS_EXPRESSION_SNIPPED)
+}
+
+/// Parses the source as a single TypeScript type, see [crate::parse_type].
+pub(crate) fn parse_type_snippet(p: &mut JsParser) -> CompletedMarker {
+    let m = p.start();
+    let ty = TypeAnnotations.parse_exclusive_syntax(
+        p,
+        |p| parse_ts_type(p, TypeContext::default()),
+        |p, ty| ts_only_syntax_error(p, "types", ty.range(p)),
+    );
+    let has_type = ty.is_present();
+
+    if !p.at(EOF) {
+        let bogus = ty.precede(p);
+        let start = p.cur_range().start();
+
+        while !p.at(EOF) {
+            p.bump_any();
+        }
+
+        let range = TextRange::new(start, p.cur_range().start());
+        let diagnostic = if has_type {
+            p.err_builder("Expected the end of the type.", range)
+                .with_hint("Only a single type can be parsed.")
+        } else {
+            expected_ts_type(p, range)
+        };
+        p.error(diagnostic);
+        bogus.complete(p, TS_BOGUS_TYPE);
+    } else if !has_type {
+        p.error(expected_ts_type(p, p.cur_range()));
+    }
+
+    m.complete(p, TS_TYPE_SNIPPED)
+}
@@ -27,7 +27,7 @@ use crate::syntax::module::parse_import_or_import_equals_declaration;
 use crate::syntax::typescript::ts_parse_error::{expected_ts_type, ts_only_syntax_error};
 
 use crate::span::Span;
-use crate::JsSyntaxFeature::{StrictMode, TypeScript};
+use crate::JsSyntaxFeature::{StrictMode, TypeAnnotations, TypeScript};
 use crate::ParsedSyntax::{Absent, Present};
 use crate::{parser, JsParser, JsSyntaxFeature, ParseRecoveryTokenSet};
 use biome_js_syntax::{JsSyntaxKind::*, *};
@@ -154,6 +154,10 @@ impl StatementContext {
 ///
 /// If not passed, [STMT_RECOVERY_SET] will be used as recovery set
 pub(crate) fn parse_statement(p: &mut JsParser, context: StatementContext) -> ParsedSyntax {
+    p.parse_nested(JS_BOGUS_STATEMENT, |p| parse_statement_base(p, context))
+}
+
+fn parse_statement_base(p: &mut JsParser, context: StatementContext) -> ParsedSyntax {
     match p.cur() {
         // test_err js import_decl_not_top_level
         // {
@@ -177,7 +181,7 @@ pub(crate) fn parse_statement(p: &mut JsParser, context: StatementContext) -> Pa
                         import.range(p),
                     )
                     .with_hint("not allowed inside scripts"),
-                ModuleKind::Module => p
+                ModuleKind::Module | ModuleKind::Unknown => p
                     .err_builder(
                         "Illegal use of an import declaration not at the top level",
                         import.range(p),
@@ -336,7 +340,7 @@ pub(crate) fn parse_statement(p: &mut JsParser, context: StatementContext) -> Pa
             // test ts ts_type_variable
             // let type;
             // type = getFlowTypeInConstructor(symbol, getDeclaringConstructor(symbol)!);
-            TypeScript.parse_exclusive_syntax(
+            TypeAnnotations.parse_exclusive_syntax(
                 p,
                 parse_ts_type_alias_declaration,
                 |p, type_alias| ts_only_syntax_error(p, "type alias", type_alias.range(p)),
@@ -380,7 +384,7 @@ pub(crate) fn parse_non_top_level_export(
 ) -> ParsedSyntax {
     parse_export(p, decorator_list).map(|mut export| {
         let error = match p.source_type().module_kind() {
-            ModuleKind::Module => p
+            ModuleKind::Module | ModuleKind::Unknown => p
                 .err_builder(
                     "Illegal use of an export declaration not at the top level",
                     export.range(p),
@@ -726,7 +730,10 @@ fn parse_return_statement(p: &mut JsParser) -> ParsedSyntax {
     let mut complete = m.complete(p, JS_RETURN_STATEMENT);
 
     // The frontmatter of Astro files is executed inside a function during the compilation, so it's safe to have illegal returns
-    if !p.state().in_function() && !p.source_type.as_embedding_kind().is_astro() {
+    if !p.state().in_function()
+        && !p.source_type.as_embedding_kind().is_astro()
+        && !p.options().allow_return_outside_function
+    {
         let err = p.err_builder(
             "Illegal return statement outside of a function",
             complete.range(p),
@@ -864,7 +871,7 @@ pub(crate) fn parse_directives(p: &mut JsParser) -> (Marker, Option<EnableStrict
 
         if directive_is_use_strict && strict_mode_snapshot.is_none() {
             strict_mode_snapshot = Some(
-                EnableStrictMode(StrictModeState::Explicit(directive_range)).apply(p.state_mut()),
+                EnableStrictMode(StrictModeState::Directive(directive_range)).apply(p.state_mut()),
             );
         }
 
@@ -910,6 +917,7 @@ pub(crate) fn parse_statements(p: &mut JsParser, stop_on_r_curly: bool, statemen
                 expected_statement,
             )
             .is_err()
+            || p.should_stop()
         {
             break;
         }
@@ -993,7 +1001,7 @@ fn parse_with_statement(p: &mut JsParser) -> ParsedSyntax {
     // or SloppyMode.exclusive_syntax(...) but this reads better with the error message, saying that
     // it's only forbidden in strict mode
     StrictMode.excluding_syntax(p, with_stmt, |p, marker| {
-        p.err_builder(
+        p.strict_mode_err_builder(
             "`with` statements are not allowed in strict mode",
             marker.range(p),
         )
@@ -1212,6 +1220,7 @@ fn eat_variable_declaration(
             p.bump(T![await]);
             p.bump(T![using]);
             context.kind_name = Some("using");
+            p.state_mut().record_await();
         }
         _ => {
             return None;
@@ -1357,7 +1366,7 @@ fn parse_variable_declarator(
         let id_range = id.range(p);
         let m = id.precede(p);
 
-        let ts_annotation = TypeScript.parse_exclusive_syntax(p, parse_ts_variable_annotation,
+        let ts_annotation = TypeAnnotations.parse_exclusive_syntax(p, parse_ts_variable_annotation,
             |p, annotation| {
                 let name = match annotation.kind(p) {
                     TS_TYPE_ANNOTATION => "type annotation",
@@ -1366,8 +1375,18 @@ fn parse_variable_declarator(
                 };
 
                 ts_only_syntax_error(p, name, annotation.range(p))
-            })
-            .ok();
+            });
+
+        // Flow supports type annotations, but not definite assignment assertions
+        let ts_annotation = match ts_annotation {
+            Present(annotation) if annotation.kind(p) == TS_DEFINITE_VARIABLE_ANNOTATION => {
+                TypeScript.exclusive_syntax(p, annotation, |p, annotation| {
+                    ts_only_syntax_error(p, "definite assertion assignments", annotation.range(p))
+                })
+            }
+            ts_annotation => ts_annotation,
+        }
+        .ok();
 
         let last_name_map = std::mem::take(&mut p.state_mut().name_map);
         let duplicate_binding_parent = p.state_mut().duplicate_binding_parent.take();
@@ -1764,6 +1783,10 @@ fn parse_for_statement(p: &mut JsParser) -> ParsedSyntax {
 
     let mut completed = m.complete(p, kind);
 
+    if kind == JS_FOR_OF_STATEMENT && await_range.is_some() {
+        p.state_mut().record_await();
+    }
+
     if kind != JS_FOR_OF_STATEMENT {
         if let Some(await_range) = await_range {
             p.error(
@@ -135,3 +135,15 @@ pub(crate) fn infer_not_allowed(p: &JsParser, range: TextRange) -> ParseDiagnost
         range,
     )
 }
+
+pub(crate) fn flow_syntax_not_supported(
+    p: &JsParser,
+    syntax: &str,
+    range: TextRange,
+) -> ParseDiagnostic {
+    p.err_builder(
+        format!("Flow {syntax} are not supported, only the syntax shared with TypeScript is."),
+        range,
+    )
+    .with_hint("The annotation is kept in the syntax tree, but isn't checked or formatted.")
+}
@@ -21,7 +21,7 @@ use crate::syntax::object::{
 use crate::syntax::stmt::optional_semi;
 use crate::syntax::typescript::try_parse;
 use crate::syntax::typescript::ts_parse_error::{
-    expected_ts_type, expected_ts_type_parameter, infer_not_allowed,
+    expected_ts_type, expected_ts_type_parameter, flow_syntax_not_supported, infer_not_allowed,
     ts_const_modifier_cannot_appear_on_a_type_parameter,
     ts_in_out_modifier_cannot_appear_on_a_type_parameter,
 };
@@ -32,7 +32,7 @@ use smallvec::SmallVec;
 use crate::lexer::{JsLexContext, JsReLexContext};
 use crate::span::Span;
 use crate::syntax::class::parse_decorators;
-use crate::JsSyntaxFeature::TypeScript;
+use crate::JsSyntaxFeature::{Flow, TypeScript};
 use crate::{Absent, JsParser, ParseRecoveryTokenSet, ParsedSyntax, Present};
 use biome_js_syntax::JsSyntaxKind::TS_TYPE_ANNOTATION;
 use biome_js_syntax::T;
@@ -168,10 +168,47 @@ pub(crate) fn parse_ts_return_type_annotation(
 
     let m = p.start();
     p.bump(T![:]);
-    parse_ts_return_type(p, context).or_add_diagnostic(p, expected_ts_type);
+
+    // Flow predicate functions, `function f(x): %checks` or `function f(x): boolean %checks`.
+    if is_at_flow_predicate(p) {
+        let predicate = p.start();
+        parse_flow_predicate(p, predicate);
+    } else if let Some(return_type) =
+        parse_ts_return_type(p, context).or_add_diagnostic(p, expected_ts_type)
+    {
+        if is_at_flow_predicate(p) {
+            let m = return_type.precede(p);
+            parse_flow_predicate(p, m);
+        }
+    }
+
     Present(m.complete(p, TS_RETURN_TYPE_ANNOTATION))
 }
 
+/// Returns `true` if the parser is at a Flow `%checks` predicate, e.g. the end of
+/// `function isString(x: mixed): boolean %checks`.
+fn is_at_flow_predicate(p: &mut JsParser) -> bool {
+    p.at(T![%])
+        && Flow.is_supported(p)
+        && p.lookahead(|p| {
+            p.bump(T![%]);
+            p.at(T![ident]) && p.cur_text() == "checks" && !p.has_preceding_line_break()
+        })
+}
+
+/// Parses `%checks` into a bogus type that also contains the return type started by `m`,
+/// if any, and reports that predicate functions aren't supported.
+fn parse_flow_predicate(p: &mut JsParser, m: Marker) {
+    p.bump(T![%]);
+    p.bump(T![ident]);
+    let predicate = m.complete(p, TS_BOGUS_TYPE);
+    p.error(flow_syntax_not_supported(
+        p,
+        "predicate functions",
+        predicate.range(p),
+    ));
+}
+
 fn parse_ts_call_signature(p: &mut JsParser, context: TypeContext) {
     parse_ts_type_parameters(p, context).ok();
     parse_parameter_list(
@@ -791,6 +828,21 @@ fn parse_ts_primary_type(p: &mut JsParser, context: TypeContext) -> ParsedSyntax
         return Present(m.complete(p, TS_TYPE_OPERATOR_TYPE));
     }
 
+    // Flow maybe types, `?string`, which include `null` and `undefined`.
+    if p.at(T![?]) && Flow.is_supported(p) {
+        let m = p.start();
+        p.bump(T![?]);
+        parse_ts_primary_type(p, context).or_add_diagnostic(p, expected_ts_type);
+        let maybe_type = m.complete(p, TS_BOGUS_TYPE);
+        p.error(flow_syntax_not_supported(
+            p,
+            "maybe types",
+            maybe_type.range(p),
+        ));
+
+        return Present(maybe_type);
+    }
+
     parse_postfix_type_or_higher(p, context.and_allow_conditional_types(true))
 }
 
@@ -1,13 +1,31 @@
+use crate::estree::{to_estree, EstreeError};
+use crate::parse::parse_common;
 use crate::test_utils::has_bogus_nodes_or_empty_slots;
-use crate::{parse, parse_module, test_utils::assert_errors_are_absent, JsParserOptions, Parse};
+use crate::util::{highlight_html, highlight_source};
+use crate::{
+    parse, parse_any, parse_expression, parse_from_lexer, parse_many, parse_module,
+    parse_retaining_events, parse_script, parse_type, parse_validate, parse_with_offset,
+    parse_with_visitor, test_utils::assert_errors_are_absent, ClassMemberKind,
+    ClassMemberVisibility, DetectedIndentation, JsLexContext, JsLexer, JsParserOptions,
+    JsReLexContext, LosslessTreeSink, LossyTreeSink, NumberValue, OptionalChainKind, Parse,
+    ParseManyError, ThisBinding, TokenSinkAdapter, TrailingCommaContext,
+};
 use biome_console::fmt::{Formatter, Termcolor};
 use biome_console::markup;
-use biome_diagnostics::DiagnosticExt;
-use biome_diagnostics::PrintDiagnostic;
-use biome_js_syntax::{AnyJsRoot, JsFileSource, JsSyntaxKind};
+use biome_diagnostics::{Diagnostic, DiagnosticExt, Location, Visit};
+use biome_diagnostics::{PrintDescription, PrintDiagnostic};
+use biome_js_factory::JsSyntaxFactory;
+use biome_js_syntax::{
+    AnyJsRoot, JsFileSource, JsLanguage, JsScript, JsSyntaxKind, LanguageVariant, LanguageVersion,
+    ModuleKind,
+};
 use biome_js_syntax::{JsCallArguments, JsLogicalExpression, JsSyntaxToken};
-use biome_rowan::{AstNode, Direction, TextSize};
+use biome_parser::lexer::BufferedLexer;
+use biome_parser::prelude::ParseDiagnostic;
+use biome_parser::tree_sink::TreeSink;
+use biome_rowan::{AstNode, AstNodeList, Direction, SyntaxKind, TextRange, TextSize};
 use expect_test::expect_file;
+use serde_json::json;
 use std::fmt::Write;
 use std::panic::catch_unwind;
 use std::path::{Path, PathBuf};
@@ -56,7 +74,7 @@ fn try_parse(path: &str, text: &str, options: JsParserOptions) -> Parse<AnyJsRoo
         let source_type = if text.contains("// SCRIPT") {
             JsFileSource::js_script()
         } else {
-            path.try_into().unwrap()
+            JsFileSource::try_from(path).unwrap()
         };
 
         let parse = parse(text, source_type, options);
@@ -156,6 +174,26 @@ fn run_and_expect_errors(path: &str, _: &str, _: &str, _: &str) {
     expect_file![path].assert_eq(&actual)
 }
 
+#[cfg(test)]
+fn run_and_expect_estree(path: &str, _: &str, _: &str, _: &str) {
+    let path = PathBuf::from(path);
+    let text = std::fs::read_to_string(&path).unwrap();
+
+    let parse = try_parse(path.to_str().unwrap(), &text, JsParserOptions::default());
+    assert_errors_are_absent(&parse, &path);
+    let program = to_estree(&parse.tree()).unwrap();
+    let actual = format!("{}\n", serde_json::to_string_pretty(&program).unwrap());
+
+    let path = path.with_extension("estree.json");
+    expect_file![path].assert_eq(&actual)
+}
+
+// The snapshots match Acorn's output, except that regex and bigint literals have a `null` value
+// where Acorn stores a `RegExp` or a `BigInt`
+mod estree {
+    tests_macros::gen_tests! {"test_data/estree/**/*.{js,cjs}", crate::tests::run_and_expect_estree, ""}
+}
+
 mod parser {
     mod ok {
         tests_macros::gen_tests! {"test_data/inline/ok/**/*.{js,ts,jsx,tsx}", crate::tests::run_and_expect_no_errors, ""}
@@ -404,6 +442,1775 @@ fn diagnostics_print_correctly() {
     }
 }
 
+#[test]
+fn confusable_identifier_with_bidi_control_character() {
+    let text = "let a\u{202E}b = 1;";
+    let root = parse_module(
+        text,
+        JsParserOptions::default().with_check_confusable_identifiers(),
+    );
+
+    let identifier = root
+        .syntax()
+        .descendants()
+        .find(|node| node.kind() == JsSyntaxKind::JS_IDENTIFIER_BINDING)
+        .unwrap();
+    assert_eq!(identifier.text_trimmed(), "a\u{202E}b");
+
+    let diagnostics = root.diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].location().span,
+        Some(identifier.text_trimmed_range())
+    );
+
+    let root = parse_module("let a = 1;", JsParserOptions::default());
+    assert!(root.diagnostics().is_empty());
+}
+
+#[test]
+fn top_level_side_effects() {
+    let declarations = r#"
+import { b } from "b";
+const a = 1;
+let c = /* @__PURE__ */ create();
+function f() { sideEffect(); }
+class A {}
+export const d = b;
+"#;
+    let root = parse_module(declarations, JsParserOptions::default());
+    assert!(!root.has_top_level_side_effects());
+
+    let call = r#"
+import { b } from "b";
+const a = 1;
+b(a);
+"#;
+    let root = parse_module(call, JsParserOptions::default());
+    assert!(root.has_top_level_side_effects());
+}
+
+#[test]
+fn class_top_level_side_effects() {
+    let cases = [
+        ("class A { a = f(); static b = 1; c() {} }", false),
+        ("class A extends B {}", false),
+        ("class A { ['a']() {} }", false),
+        ("const A = class { static a = 1; };", false),
+        ("class A { static { f(); } }", true),
+        ("class A { static a = f(); }", true),
+        ("class A { [f()]() {} }", true),
+        ("class A { [a] = 1; }", true),
+        ("class A extends f() {}", true),
+        ("export class A { static { f(); } }", true),
+        ("export default class extends f() {}", true),
+        ("const A = class { static a = f(); };", true),
+        ("@dec class A {}", true),
+    ];
+
+    for (code, has_side_effects) in cases {
+        let root = parse(
+            code,
+            JsFileSource::js_module().with_version(LanguageVersion::ESNext),
+            JsParserOptions::default(),
+        );
+        assert!(root.diagnostics().is_empty(), "{code}");
+        assert_eq!(
+            root.has_top_level_side_effects(),
+            has_side_effects,
+            "{code}"
+        );
+    }
+}
+
+#[test]
+fn string_concat_chains() {
+    let code = r#"let s = "a" + x + "b";"#;
+    let root = parse_module(code, JsParserOptions::default());
+    let chains = root.string_concat_chains();
+
+    assert_eq!(chains.len(), 1);
+    assert_eq!(&code[chains[0].range], r#""a" + x + "b""#);
+    let operands: Vec<_> = chains[0]
+        .operands
+        .iter()
+        .map(|operand| operand.syntax().text_trimmed().to_string())
+        .collect();
+    assert_eq!(operands, [r#""a""#, "x", r#""b""#]);
+
+    let root = parse_module("let n = 1 + x + 2;", JsParserOptions::default());
+    assert!(root.string_concat_chains().is_empty());
+}
+
+#[test]
+fn trailing_comma_report() {
+    let code = r#"let a = [1, 2,]; let b = { c: 1, d: 2 };"#;
+    let root = parse_module(code, JsParserOptions::default());
+    let report = root.trailing_comma_report();
+
+    assert_eq!(report.len(), 2);
+    assert_eq!(report[0].context, TrailingCommaContext::Array);
+    assert_eq!(&code[report[0].range], "1, 2,");
+    assert!(report[0].has_trailing_comma);
+    assert_eq!(report[1].context, TrailingCommaContext::Object);
+    assert_eq!(&code[report[1].range], "c: 1, d: 2");
+    assert!(!report[1].has_trailing_comma);
+}
+
+#[test]
+fn shebang_in_definition_file() {
+    let code = "#!/usr/bin/env node\ndeclare const a: string;";
+
+    let root = parse(code, JsFileSource::d_ts(), JsParserOptions::default());
+    assert_eq!(root.diagnostics().len(), 1);
+    assert!(root.has_errors());
+
+    let root = parse(code, JsFileSource::ts(), JsParserOptions::default());
+    assert!(root.diagnostics().is_empty());
+}
+
+#[test]
+fn function_body_in_definition_file() {
+    assert!(JsFileSource::d_ts().is_definition_file());
+    assert!(!JsFileSource::ts().is_definition_file());
+
+    let root = parse(
+        "declare function f(): void;",
+        JsFileSource::d_ts(),
+        JsParserOptions::default(),
+    );
+    assert!(root.diagnostics().is_empty());
+
+    let root = parse(
+        "function f() { return 1; }",
+        JsFileSource::d_ts(),
+        JsParserOptions::default(),
+    );
+    assert_eq!(root.diagnostics().len(), 1);
+    assert_eq!(
+        PrintDescription(&root.diagnostics()[0]).to_string(),
+        "A 'declare' function cannot have a function body"
+    );
+
+    let root = parse(
+        "function f() { return 1; }",
+        JsFileSource::ts(),
+        JsParserOptions::default(),
+    );
+    assert!(root.diagnostics().is_empty());
+}
+
+#[test]
+fn import_section() {
+    let code = r#"import a from "a";
+// b is used below
+import b from "b";
+import { c } from "c";
+
+const d = a + b + c;
+"#;
+    let root = parse_module(code, JsParserOptions::default());
+    let section = root.import_section();
+
+    assert_eq!(section.imports.len(), 3);
+    assert_eq!(
+        &code[usize::from(section.end)..],
+        "\n\nconst d = a + b + c;\n"
+    );
+}
+
+#[test]
+fn proto_setters() {
+    let code = "let a = { __proto__: o };";
+    let root = parse_module(code, JsParserOptions::default());
+    let setters = root.proto_setters();
+
+    assert_eq!(setters.len(), 1);
+    assert_eq!(&code[setters[0]], "__proto__: o");
+
+    let root = parse_module(
+        r#"let a = { ["__proto__"]: o };"#,
+        JsParserOptions::default(),
+    );
+    assert!(root.proto_setters().is_empty());
+}
+
+#[test]
+fn is_effectively_empty() {
+    let root = parse_module("", JsParserOptions::default());
+    assert!(root.is_effectively_empty());
+
+    let root = parse_module(
+        "/*\n * Licensed under the MIT license.\n */\n",
+        JsParserOptions::default(),
+    );
+    assert!(root.is_effectively_empty());
+
+    let root = parse_module("/* header */\nlet a;", JsParserOptions::default());
+    assert!(!root.is_effectively_empty());
+}
+
+#[test]
+fn optional_chains() {
+    let code = "a?.b; a?.(); a.b.c; a?.b.c();";
+    let root = parse_module(code, JsParserOptions::default());
+    let chains = root.optional_chains();
+
+    assert_eq!(chains.len(), 3);
+    assert_eq!(chains[0].kind, OptionalChainKind::Member);
+    assert_eq!(&code[chains[0].range], "a?.b");
+    assert_eq!(&code[chains[0].operator_range], "?.");
+    assert_eq!(chains[1].kind, OptionalChainKind::Call);
+    assert_eq!(&code[chains[1].range], "a?.()");
+    assert_eq!(chains[2].kind, OptionalChainKind::Member);
+    assert_eq!(&code[chains[2].range], "a?.b");
+}
+
+#[test]
+fn computed_member_accesses() {
+    let code = r#"obj["a"]; obj[k]; obj[k] = 1;"#;
+    let root = parse_module(code, JsParserOptions::default());
+    let accesses = root.computed_member_accesses();
+
+    assert_eq!(accesses.len(), 3);
+    assert!(accesses[0].is_static);
+    assert_eq!(&code[accesses[0].range], r#"obj["a"]"#);
+    assert_eq!(&code[accesses[0].object_range], "obj");
+    assert_eq!(&code[accesses[0].key_range], r#""a""#);
+    assert!(!accesses[1].is_static);
+    assert_eq!(&code[accesses[1].key_range], "k");
+    assert!(!accesses[2].is_static);
+    assert_eq!(&code[accesses[2].range], "obj[k]");
+}
+
+#[test]
+fn this_references() {
+    let code = r#"
+class A {
+    method() {
+        return () => this.value;
+    }
+    [this.key]() {}
+}
+"#;
+    let root = parse_module(code, JsParserOptions::default());
+    let references = root.this_references();
+
+    assert_eq!(references.len(), 2);
+    assert_eq!(references[0].binding, ThisBinding::Method);
+    assert!(references[0].in_arrow);
+    let method = references[0].binding_range.unwrap();
+    assert!(code[method].starts_with("method()"));
+    assert_eq!(references[1].binding, ThisBinding::TopLevel);
+    assert!(!references[1].in_arrow);
+}
+
+#[test]
+fn render_diagnostics() {
+    let source = "let a = 1;\nlet b = ;\n";
+    let root = parse_module(source, JsParserOptions::default());
+    let report = root.render_diagnostics("example.js", source);
+
+    assert!(report.contains("example.js:2:9"), "{report}");
+    assert!(
+        report
+            .lines()
+            .any(|line| line.trim_end().ends_with("│         ^")),
+        "{report}"
+    );
+}
+
+#[test]
+fn strict_mode_and_source_type() {
+    let script = parse_script("'use strict';\na = 1;", JsParserOptions::default());
+    assert!(script.is_strict());
+
+    let script = parse_script("a = 1;\n'use strict';", JsParserOptions::default());
+    assert!(!script.is_strict());
+    assert_eq!(
+        script.source_type().map(|source| source.module_kind()),
+        Some(ModuleKind::Script)
+    );
+
+    let module = parse(
+        "a = 1;",
+        JsFileSource::js_module().with_version(LanguageVersion::ES2022),
+        JsParserOptions::default(),
+    );
+    assert!(module.is_strict());
+    assert_eq!(
+        module.source_type().map(|source| source.version()),
+        Some(LanguageVersion::ES2022)
+    );
+
+    let expression = parse_expression(
+        "a + 1",
+        JsFileSource::js_script(),
+        JsParserOptions::default(),
+    );
+    assert_eq!(expression.module_kind(), ModuleKind::Script);
+    assert!(!expression.is_strict());
+
+    let reparsed = expression.reparse(
+        TextRange::new(TextSize::from(4), TextSize::from(5)),
+        "b",
+        JsParserOptions::default(),
+    );
+    assert_eq!(
+        reparsed.syntax().kind(),
+        JsSyntaxKind::JS_EXPRESSION_SNIPPED
+    );
+    assert_eq!(reparsed.module_kind(), ModuleKind::Script);
+    assert_eq!(reparsed.source_type(), Some(JsFileSource::js_script()));
+
+    let expression = parse_expression(
+        "a + 1",
+        JsFileSource::js_module(),
+        JsParserOptions::default(),
+    );
+    assert_eq!(expression.module_kind(), ModuleKind::Module);
+    assert!(expression.is_strict());
+}
+
+#[test]
+fn class_members() {
+    let code = r#"
+class A {
+    static create() {}
+    get value() {}
+    #count = 0;
+}
+"#;
+    let root = parse_module(code, JsParserOptions::default());
+    let classes = root.class_members();
+
+    assert_eq!(classes.len(), 1);
+    assert_eq!(classes[0].name.as_deref(), Some("A"));
+
+    let members: Vec<_> = classes[0]
+        .members
+        .iter()
+        .map(|member| {
+            (
+                member.name.as_str(),
+                member.kind,
+                member.is_static,
+                member.visibility,
+            )
+        })
+        .collect();
+    assert_eq!(
+        members,
+        [
+            (
+                "create",
+                ClassMemberKind::Method,
+                true,
+                ClassMemberVisibility::Public
+            ),
+            (
+                "value",
+                ClassMemberKind::Getter,
+                false,
+                ClassMemberVisibility::Public
+            ),
+            (
+                "#count",
+                ClassMemberKind::Field,
+                false,
+                ClassMemberVisibility::Private
+            ),
+        ]
+    );
+}
+
+#[test]
+fn numeric_literals() {
+    let root = parse_module("a = [0x1F, 1_000, 10n];", JsParserOptions::default());
+    let literals = root.numeric_literals();
+
+    let values: Vec<_> = literals
+        .iter()
+        .map(|literal| (literal.text.as_str(), literal.value))
+        .collect();
+    assert_eq!(
+        values,
+        [
+            ("0x1F", NumberValue::Integer(31.0)),
+            ("1_000", NumberValue::Integer(1000.0)),
+            ("10n", NumberValue::BigInt(Some(10))),
+        ]
+    );
+}
+
+#[test]
+fn syntax_newer_than_configured_version() {
+    let es2018 = JsFileSource::js_module().with_version(LanguageVersion::ES2018);
+
+    let root = parse("class A { static {} }", es2018, JsParserOptions::default());
+    assert!(root
+        .syntax()
+        .descendants()
+        .any(|node| node.kind() == JsSyntaxKind::JS_BOGUS_MEMBER));
+    assert_eq!(root.diagnostics().len(), 1);
+    let message = format!("{:?}", root.diagnostics()[0]);
+    assert!(message.contains("ES2022") && message.contains("ES2018"));
+
+    let root = parse("await test();", es2018, JsParserOptions::default());
+    assert!(root
+        .syntax()
+        .descendants()
+        .any(|node| node.kind() == JsSyntaxKind::JS_BOGUS_EXPRESSION));
+    assert_eq!(root.diagnostics().len(), 1);
+
+    let es2022 = JsFileSource::js_module().with_version(LanguageVersion::ES2022);
+    let root = parse(
+        "class A { static {} }\nawait test();",
+        es2022,
+        JsParserOptions::default(),
+    );
+    assert!(root.diagnostics().is_empty());
+}
+
+#[test]
+fn decorators_require_typescript_or_esnext() {
+    let code = "@dec class A {}";
+
+    let ts = parse(
+        code,
+        JsFileSource::ts().with_version(LanguageVersion::ES2022),
+        JsParserOptions::default(),
+    );
+    assert!(ts.diagnostics().is_empty());
+
+    let es2022 = parse(
+        code,
+        JsFileSource::js_module().with_version(LanguageVersion::ES2022),
+        JsParserOptions::default(),
+    );
+    assert_eq!(es2022.diagnostics().len(), 1);
+    assert!(format!("{:?}", es2022.diagnostics()[0]).contains("ES2022"));
+    assert!(es2022
+        .syntax()
+        .descendants()
+        .any(|node| node.kind() == JsSyntaxKind::JS_BOGUS));
+
+    let esnext = parse(
+        code,
+        JsFileSource::js_module().with_version(LanguageVersion::ESNext),
+        JsParserOptions::default(),
+    );
+    assert!(esnext.diagnostics().is_empty());
+    assert!(esnext
+        .syntax()
+        .descendants()
+        .any(|node| node.kind() == JsSyntaxKind::JS_DECORATOR));
+
+    // Source types inferred from a path target ESNext
+    let js_file = parse(
+        code,
+        JsFileSource::try_from(Path::new("index.js")).unwrap(),
+        JsParserOptions::default(),
+    );
+    assert!(js_file.diagnostics().is_empty());
+    assert!(js_file
+        .syntax()
+        .descendants()
+        .any(|node| node.kind() == JsSyntaxKind::JS_DECORATOR));
+}
+
+#[test]
+fn accessor_modifier_requires_typescript_or_esnext() {
+    let code = "class A { @dec accessor a = 1; }";
+
+    let es2022 = parse(
+        code,
+        JsFileSource::js_module().with_version(LanguageVersion::ES2022),
+        JsParserOptions::default(),
+    );
+    assert!(es2022
+        .diagnostics()
+        .iter()
+        .any(|diagnostic| format!("{diagnostic:?}").contains("'accessor' modifier")));
+
+    for source_type in [
+        JsFileSource::js_module().with_version(LanguageVersion::ESNext),
+        JsFileSource::ts().with_version(LanguageVersion::ES2022),
+    ] {
+        let root = parse(code, source_type, JsParserOptions::default());
+        assert!(root.diagnostics().is_empty());
+        assert!(root
+            .syntax()
+            .descendants()
+            .any(|node| node.kind() == JsSyntaxKind::JS_ACCESSOR_MODIFIER));
+    }
+
+    // Source types inferred from a path target ESNext
+    let root = parse(
+        "class A { accessor x = 1 }",
+        JsFileSource::try_from(Path::new("index.js")).unwrap(),
+        JsParserOptions::default(),
+    );
+    assert!(root.diagnostics().is_empty());
+    assert!(root
+        .syntax()
+        .descendants()
+        .any(|node| node.kind() == JsSyntaxKind::JS_ACCESSOR_MODIFIER));
+
+    let root = parse(
+        "class A { accessor = 1; accessor() {} }",
+        JsFileSource::js_module().with_version(LanguageVersion::ES2022),
+        JsParserOptions::default(),
+    );
+    assert!(root.diagnostics().is_empty());
+}
+
+#[test]
+fn namespace_reexport_version() {
+    let code = r#"export * as ns from "m";"#;
+
+    let root = parse(code, JsFileSource::js_module(), JsParserOptions::default());
+    assert!(root.diagnostics().is_empty());
+    assert!(root
+        .syntax()
+        .descendants()
+        .any(|node| node.kind() == JsSyntaxKind::JS_EXPORT_AS_CLAUSE));
+
+    let es2019 = JsFileSource::js_module().with_version(LanguageVersion::ES2019);
+    let root = parse(code, es2019, JsParserOptions::default());
+    assert_eq!(root.diagnostics().len(), 1);
+    let message = format!("{:?}", root.diagnostics()[0]);
+    assert!(message.contains("ES2020") && message.contains("ES2019"));
+    assert!(root
+        .syntax()
+        .descendants()
+        .any(|node| node.kind() == JsSyntaxKind::JS_EXPORT_FROM_CLAUSE));
+
+    let root = parse(r#"export * from "m";"#, es2019, JsParserOptions::default());
+    assert!(root.diagnostics().is_empty());
+}
+
+#[test]
+fn syntax_gated_on_language_version() {
+    use LanguageVersion::*;
+
+    for (code, unsupported, supported) in [
+        ("a?.b;", ES2019, ES2020),
+        ("a?.[b];", ES2019, ES2020),
+        ("a?.();", ES2019, ES2020),
+        ("a ?? b;", ES2019, ES2020),
+        ("a ??= b;", ES2020, ES2021),
+        ("a ||= b;", ES2020, ES2021),
+        ("a &&= b;", ES2020, ES2021),
+        ("class A { a = 1; }", ES2021, ES2022),
+        ("class A { #a; }", ES2021, ES2022),
+    ] {
+        let source_type = JsFileSource::js_module().with_version(unsupported);
+        let root = parse(code, source_type, JsParserOptions::default());
+        assert_eq!(root.diagnostics().len(), 1, "{code}");
+        let message = format!("{:?}", root.diagnostics()[0]);
+        assert!(message.contains(&format!("{supported:?}")), "{code}");
+
+        let source_type = JsFileSource::js_module().with_version(supported);
+        let root = parse(code, source_type, JsParserOptions::default());
+        assert!(root.diagnostics().is_empty(), "{code}");
+    }
+}
+
+#[test]
+fn import_meta_in_module_and_script() {
+    let code = "console.log(import.meta.url);";
+
+    let module = parse(code, JsFileSource::js_module(), JsParserOptions::default());
+    assert!(module.diagnostics().is_empty());
+
+    let script = parse(code, JsFileSource::js_script(), JsParserOptions::default());
+    assert_eq!(script.diagnostics().len(), 1);
+    assert!(script
+        .syntax()
+        .descendants()
+        .any(|node| node.kind() == JsSyntaxKind::JS_IMPORT_META_EXPRESSION));
+}
+
+#[test]
+fn new_target_inside_and_outside_function() {
+    let inside = [
+        "function f() { return new.target; }",
+        "function f() { return () => new.target; }",
+        "function f(a = new.target) {}",
+        "class A { constructor() { new.target; } }",
+        "class A { a = new.target; }",
+        "class A { static { new.target; } }",
+    ];
+
+    for code in inside {
+        let root = parse(code, JsFileSource::js_module(), JsParserOptions::default());
+        assert!(root.diagnostics().is_empty(), "{code}");
+    }
+
+    let root = parse(
+        "if (new.target) {}",
+        JsFileSource::js_module(),
+        JsParserOptions::default(),
+    );
+    assert_eq!(root.diagnostics().len(), 1);
+    assert!(root
+        .syntax()
+        .descendants()
+        .any(|node| node.kind() == JsSyntaxKind::JS_NEW_TARGET_EXPRESSION));
+}
+
+#[test]
+fn parse_any_detects_module_kind() {
+    let source_type = JsFileSource::js_module().with_module_kind(ModuleKind::Unknown);
+    let cases = [
+        ("export {};", ModuleKind::Module),
+        ("import a from \"a\";", ModuleKind::Module),
+        ("console.log(import.meta.url);", ModuleKind::Module),
+        (
+            "function f() { return `${import.meta.url}`; }",
+            ModuleKind::Module,
+        ),
+        (
+            "import(\"./a.js\").then(a => a.export);",
+            ModuleKind::Script,
+        ),
+        ("const a = { import: 1, export() {} };", ModuleKind::Script),
+        ("const a = `${b} import a from \"a\"`;", ModuleKind::Script),
+        ("module.exports = require(\"a\");", ModuleKind::Script),
+    ];
+
+    for (code, module_kind) in cases {
+        let root = parse_any(code, source_type, JsParserOptions::default());
+        assert_eq!(root.module_kind(), module_kind, "{code}");
+        assert!(root.diagnostics().is_empty(), "{code}");
+    }
+
+    let root = parse_any(
+        "import(\"./a.js\");",
+        JsFileSource::js_module(),
+        JsParserOptions::default(),
+    );
+    assert_eq!(root.module_kind(), ModuleKind::Module);
+}
+
+#[test]
+fn detect_indentation() {
+    let code = r#"function f(a) {
+  if (a) {
+    return {
+      b: 1,
+    };
+  }
+}
+"#;
+    let root = parse_module(code, JsParserOptions::default());
+    assert_eq!(
+        root.detect_indentation(),
+        Some(DetectedIndentation::Spaces(2))
+    );
+
+    let root = parse_module("f();\ng();", JsParserOptions::default());
+    assert_eq!(root.detect_indentation(), None);
+
+    let code = format!("if (a) {{\n{}b();\n}}", " ".repeat(300));
+    let root = parse_module(&code, JsParserOptions::default());
+    assert_eq!(
+        root.detect_indentation(),
+        Some(DetectedIndentation::Spaces(u8::MAX))
+    );
+}
+
+#[test]
+fn max_diagnostics() {
+    let code = "let = 1 +;\n".repeat(100);
+
+    let unlimited = parse_module(&code, JsParserOptions::default());
+    assert!(unlimited.diagnostics().len() > 50);
+
+    let limited = parse_module(&code, JsParserOptions::default().with_max_diagnostics(50));
+    assert_eq!(limited.diagnostics().len(), 51);
+    assert_eq!(
+        format!("{:#?}", unlimited.syntax()),
+        format!("{:#?}", limited.syntax())
+    );
+    let message = format!("{:?}", limited.diagnostics()[50]);
+    let omitted = unlimited.diagnostics().len() - 50;
+    assert!(message.contains(&format!("{omitted} more were truncated")));
+
+    // Lexer diagnostics count towards the limit too
+    let code = format!("{code}'unterminated");
+    let limited = parse_module(&code, JsParserOptions::default().with_max_diagnostics(50));
+    assert_eq!(limited.diagnostics().len(), 51);
+}
+
+#[test]
+fn errors_and_warnings() {
+    let root = parse_module("let a = 1;", JsParserOptions::default());
+    assert!(!root.has_errors());
+    assert!(root.ok().is_ok());
+
+    let options = JsParserOptions::default().with_max_diagnostics(1);
+    let root = parse_module("let = 1;\nlet = 2;", options.clone());
+    assert!(root.has_errors());
+    assert_eq!(root.errors().count(), 1);
+    assert_eq!(root.warnings().count(), 1);
+    assert_eq!(root.ok().unwrap_err().len(), 1);
+
+    let root = parse_module("let a = 1;", options);
+    assert_eq!(root.warnings().count(), 0);
+}
+
+#[test]
+fn unsafe_integers() {
+    let options = JsParserOptions::default().with_check_unsafe_integers();
+
+    let code = "let a = 9007199254740993;";
+    let root = parse_module(code, options.clone());
+    assert!(!root.has_errors());
+    assert_eq!(root.warnings().count(), 1);
+    let range = root.warnings().next().unwrap().location().span.unwrap();
+    assert_eq!(&code[range], "9007199254740993");
+    assert!(root
+        .syntax()
+        .descendants()
+        .any(|node| node.kind() == JsSyntaxKind::JS_NUMBER_LITERAL_EXPRESSION));
+
+    let root = parse_module("let a = 42;", options);
+    assert!(root.diagnostics().is_empty());
+
+    let root = parse_module(code, JsParserOptions::default());
+    assert!(root.diagnostics().is_empty());
+}
+
+#[test]
+fn parse_fragment_with_offset() {
+    let mut document = format!("{:<112}<script>", "<template><div></div></template>");
+    let fragment = "\nlet a = 1;\nlet b = ;\n";
+    assert_eq!(document.len(), 120);
+    document.push_str(fragment);
+    document.push_str("</script>");
+
+    let root = parse_with_offset(
+        fragment,
+        TextSize::from(120),
+        JsFileSource::js_module(),
+        JsParserOptions::default(),
+    );
+
+    assert_eq!(root.syntax().text_range().start(), TextSize::from(120));
+    assert_eq!(&document[root.syntax().text_range()], fragment);
+    for token in root.syntax().descendants_tokens(Direction::Next) {
+        assert_eq!(&document[token.text_range()], token.text());
+    }
+
+    assert_eq!(root.diagnostics().len(), 1);
+    let range = root.diagnostics()[0].location().span.unwrap();
+    assert!(range.start() >= TextSize::from(120));
+    assert_eq!(&document[range], ";");
+}
+
+#[test]
+fn token_sink_adapter() {
+    let source = "a + b;";
+    let mut sink = TokenSinkAdapter::new(source);
+    parse_with_visitor(
+        source,
+        JsFileSource::js_module(),
+        JsParserOptions::default(),
+        &mut sink,
+    );
+
+    assert!(sink.diagnostics().is_empty());
+    assert_eq!(
+        sink.tokens(),
+        [
+            (JsSyntaxKind::IDENT, TextRange::new(0.into(), 1.into())),
+            (JsSyntaxKind::PLUS, TextRange::new(2.into(), 3.into())),
+            (JsSyntaxKind::IDENT, TextRange::new(4.into(), 5.into())),
+            (JsSyntaxKind::SEMICOLON, TextRange::new(5.into(), 6.into())),
+        ]
+    );
+
+    let source = "/* a */ `  b ${c}`";
+    let mut sink = TokenSinkAdapter::new(source);
+    parse_with_visitor(
+        source,
+        JsFileSource::js_module(),
+        JsParserOptions::default(),
+        &mut sink,
+    );
+    let (tokens, _) = sink.finish();
+    let texts: Vec<_> = tokens.iter().map(|(_, range)| &source[*range]).collect();
+    assert_eq!(texts, ["`", "  b ", "${", "c", "}", "`"]);
+
+    let source = "a +;";
+    let mut sink = TokenSinkAdapter::new(source);
+    parse_with_visitor(
+        source,
+        JsFileSource::js_module(),
+        JsParserOptions::default(),
+        &mut sink,
+    );
+    assert_eq!(sink.diagnostics().len(), 1);
+}
+
+#[test]
+fn parse_with_visitor_sink() {
+    #[derive(Default)]
+    struct NodeKinds(Vec<JsSyntaxKind>);
+
+    impl TreeSink for NodeKinds {
+        type Kind = JsSyntaxKind;
+
+        fn token(&mut self, _kind: JsSyntaxKind, _end: TextSize) {}
+
+        fn start_node(&mut self, kind: JsSyntaxKind) {
+            self.0.push(kind);
+        }
+
+        fn finish_node(&mut self) {}
+
+        fn errors(&mut self, _errors: Vec<ParseDiagnostic>) {}
+    }
+
+    let mut sink = NodeKinds::default();
+    parse_with_visitor(
+        "f(a);",
+        JsFileSource::js_module(),
+        JsParserOptions::default(),
+        &mut sink,
+    );
+
+    assert_eq!(
+        sink.0,
+        [
+            JsSyntaxKind::JS_MODULE,
+            JsSyntaxKind::JS_DIRECTIVE_LIST,
+            JsSyntaxKind::JS_MODULE_ITEM_LIST,
+            JsSyntaxKind::JS_EXPRESSION_STATEMENT,
+            JsSyntaxKind::JS_CALL_EXPRESSION,
+            JsSyntaxKind::JS_IDENTIFIER_EXPRESSION,
+            JsSyntaxKind::JS_REFERENCE_IDENTIFIER,
+            JsSyntaxKind::JS_CALL_ARGUMENTS,
+            JsSyntaxKind::JS_CALL_ARGUMENT_LIST,
+            JsSyntaxKind::JS_IDENTIFIER_EXPRESSION,
+            JsSyntaxKind::JS_REFERENCE_IDENTIFIER,
+        ]
+    );
+}
+
+#[test]
+fn lossy_tree_sink() {
+    let code = r#"
+// leading comment
+function f(a, b) {
+    /* block */ return a + b; // trailing
+}
+"#;
+    let (events, errors, trivia, _, _) =
+        parse_common(code, JsFileSource::js_module(), JsParserOptions::default());
+
+    let mut lossless = LosslessTreeSink::<JsLanguage, JsSyntaxFactory>::new(code, &trivia);
+    biome_parser::event::process(&mut lossless, events.clone(), errors.clone());
+    let (lossless, _) = lossless.finish();
+
+    let mut lossy = LossyTreeSink::<JsLanguage, JsSyntaxFactory>::new(code, &trivia);
+    biome_parser::event::process(&mut lossy, events, errors);
+    let (lossy, _, source_map) = lossy.finish();
+
+    assert_eq!(lossless.descendants().count(), lossy.descendants().count());
+    assert_eq!(
+        lossless.descendants_with_tokens(Direction::Next).count(),
+        lossy.descendants_with_tokens(Direction::Next).count()
+    );
+    assert!(lossy.descendants_tokens(Direction::Next).all(|token| {
+        token.leading_trivia().pieces().next().is_none()
+            && token.trailing_trivia().pieces().next().is_none()
+    }));
+    assert_eq!(lossy.text().to_string(), "functionf(a,b){returna+b;}");
+
+    for (lossless, lossy) in lossless.descendants().zip(lossy.descendants()) {
+        assert_eq!(lossless.kind(), lossy.kind());
+        if !lossy.text_range().is_empty() {
+            assert_eq!(
+                source_map.original_range(lossy.text_range()),
+                lossless.text_trimmed_range(),
+                "{lossy:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn jsx_in_js_files() {
+    let code = "const a = <div />;";
+
+    let standard = parse(code, JsFileSource::js_module(), JsParserOptions::default());
+    assert!(standard.has_errors());
+
+    let jsx = parse(
+        code,
+        JsFileSource::js_module().with_variant(LanguageVariant::Jsx),
+        JsParserOptions::default(),
+    );
+    assert!(!jsx.has_errors(), "{:?}", jsx.diagnostics());
+
+    // `.js` and `.mjs` files may contain JSX, so they're parsed with the JSX variant.
+    for extension in ["js", "mjs"] {
+        let source_type = JsFileSource::try_from_extension(extension).unwrap();
+        assert!(!parse(code, source_type, JsParserOptions::default()).has_errors());
+    }
+}
+
+#[test]
+fn reprocess_retained_events() {
+    let code = "// comment\nconst a = f(b, c);\nlet = ;";
+    let parse = parse_retaining_events(code, JsFileSource::js_module(), JsParserOptions::default());
+    let tree = parse.syntax();
+    assert!(parse.has_errors());
+    let retained = parse.into_events().unwrap();
+
+    let mut sink = LosslessTreeSink::<JsLanguage, JsSyntaxFactory>::new(code, &retained.trivia);
+    biome_parser::event::process(&mut sink, retained.events, Vec::new());
+    let (rebuilt, _) = sink.finish();
+
+    assert_eq!(format!("{tree:#?}"), format!("{rebuilt:#?}"));
+    assert_eq!(rebuilt.text().to_string(), code);
+    assert!(parse_module(code, JsParserOptions::default())
+        .into_events()
+        .is_none());
+}
+
+#[test]
+fn reparse_edits() {
+    let code = "function f() {\n    return 'hello';\n}\n";
+    let parse = parse_module(code, JsParserOptions::default());
+
+    let literal_start = TextSize::from(code.find("hello").unwrap() as u32);
+    let edit = TextRange::at(literal_start, TextSize::from(5));
+    let reparsed = parse.reparse(edit, "world", JsParserOptions::default());
+    let expected = parse_module(
+        "function f() {\n    return 'world';\n}\n",
+        JsParserOptions::default(),
+    );
+    assert_eq!(
+        format!("{:#?}", reparsed.syntax()),
+        format!("{:#?}", expected.syntax())
+    );
+
+    let edit = TextRange::empty(TextSize::from(code.find("return").unwrap() as u32));
+    let reparsed = parse.reparse(edit, "{ ", JsParserOptions::default());
+    let expected = parse_module(
+        "function f() {\n    { return 'hello';\n}\n",
+        JsParserOptions::default(),
+    );
+    assert_eq!(
+        format!("{:#?}", reparsed.syntax()),
+        format!("{:#?}", expected.syntax())
+    );
+    assert_eq!(reparsed.diagnostics().len(), expected.diagnostics().len());
+    assert!(reparsed.has_errors());
+}
+
+#[test]
+fn reparse_statement_edits() {
+    let code = "let a = 1;\nfunction f() {\n    return a;\n}\nb = 2;\n(c);\n";
+    let parse = parse_module(code, JsParserOptions::default());
+
+    for (needle, len, replacement) in [
+        // Only the function declaration changes
+        ("return a", 8, "return a + 1"),
+        // Still a single statement after the edit
+        ("let a = 1", 9, "let a = [1, 2]"),
+        // Removing the semicolon makes `b = 2` call `(c)`
+        ("2;", 2, "2"),
+        // Splitting a statement in two
+        ("1;", 2, "1; let d = 3;"),
+        // Unterminated function body swallows the remaining statements
+        ("}\n", 1, ""),
+    ] {
+        let start = TextSize::from(code.find(needle).unwrap() as u32);
+        let edit = TextRange::at(start, TextSize::from(len));
+        let reparsed = parse.reparse(edit, replacement, JsParserOptions::default());
+
+        let mut text = code.to_string();
+        text.replace_range(std::ops::Range::<usize>::from(edit), replacement);
+        let expected = parse_module(&text, JsParserOptions::default());
+        assert_eq!(
+            format!("{:#?}", reparsed.syntax()),
+            format!("{:#?}", expected.syntax()),
+            "{text}"
+        );
+        assert_eq!(reparsed.diagnostics().len(), expected.diagnostics().len());
+    }
+}
+
+#[test]
+fn highlight_ansi() {
+    let highlighted = highlight_source("const x = 1; // one", JsFileSource::js_module());
+
+    assert!(highlighted.starts_with("\x1b[35mconst\x1b[0m x "));
+    assert!(highlighted.contains("\x1b[33m1\x1b[0m"));
+    assert!(highlighted.contains("\x1b[90m// one\x1b[0m"));
+
+    let highlighted = highlight_source("abstract class A {}", JsFileSource::js_module());
+    assert!(highlighted.starts_with("\x1b[4;31mabstract\x1b[0m"));
+}
+
+#[test]
+fn highlight_html_jsx() {
+    let code = "const a = <div title=\"a & b\">{`x\ny`}</div>; /* one\ntwo */";
+    let html = highlight_html(code, JsFileSource::jsx());
+
+    assert_eq!(
+        html.matches("<span ").count(),
+        html.matches("</span>").count()
+    );
+    assert!(!html.contains("<div"));
+    assert!(html.contains(r#"<span class="string">&quot;a &amp; b&quot;</span>"#));
+    assert!(html.contains("<span class=\"string\">x\ny</span>"));
+    assert!(html.contains("<span class=\"comment\">/* one\ntwo */</span>"));
+}
+
+#[test]
+fn flow_type_annotations() {
+    let code = "function f(x: number): number { return x; }\nlet a: string = 'a';";
+
+    let flow = parse(code, JsFileSource::flow(), JsParserOptions::default());
+    assert!(!flow.has_errors(), "{:?}", flow.diagnostics());
+    assert!(!has_bogus_nodes_or_empty_slots(&flow.syntax()));
+
+    let js = parse(code, JsFileSource::js_module(), JsParserOptions::default());
+    assert!(js.has_errors());
+
+    let code = r#"function g<T>(x?: T, ...rest: Array<T>): void {}
+const h = (x: number): number => x;
+class A<T> { t: T; m<U>(u: U): T { return this.t; } }
+const o = { m<T>(x: T): T { return x; }, get a(): number { return 1; } };
+type Pair<T> = [T, T];"#;
+    let flow = parse(code, JsFileSource::flow(), JsParserOptions::default());
+    assert!(!flow.has_errors(), "{:?}", flow.diagnostics());
+    assert!(!has_bogus_nodes_or_empty_slots(&flow.syntax()));
+}
+
+#[test]
+fn flow_rejects_typescript_only_syntax() {
+    for code in [
+        "enum A {}",
+        "interface A {}",
+        "namespace A {}",
+        "a as string;",
+        "a!;",
+        "abstract class A {}",
+        "declare const a: string;",
+        "let a!: string;",
+    ] {
+        let flow = parse(code, JsFileSource::flow(), JsParserOptions::default());
+        assert!(
+            flow.diagnostics()
+                .iter()
+                .any(|diagnostic| format!("{diagnostic:?}").contains("TypeScript")),
+            "{code}: {:?}",
+            flow.diagnostics()
+        );
+    }
+}
+
+#[test]
+fn flow_predicate_function() {
+    let code = "function f(x): boolean %checks {}";
+
+    let flow = parse(code, JsFileSource::flow(), JsParserOptions::default());
+    assert_eq!(flow.syntax().to_string(), code);
+    assert_eq!(flow.diagnostics().len(), 1);
+
+    let module = flow.tree();
+    let AnyJsRoot::JsModule(module) = module else {
+        panic!("expected a module");
+    };
+    let function = module.items().iter().next().unwrap();
+    assert_eq!(
+        function.syntax().kind(),
+        JsSyntaxKind::JS_FUNCTION_DECLARATION
+    );
+    let annotation = function
+        .syntax()
+        .children()
+        .find(|node| node.kind() == JsSyntaxKind::TS_RETURN_TYPE_ANNOTATION)
+        .unwrap();
+    assert_eq!(annotation.text_trimmed(), ": boolean %checks");
+    assert_eq!(
+        annotation.last_child().unwrap().kind(),
+        JsSyntaxKind::TS_BOGUS_TYPE
+    );
+    assert!(function
+        .syntax()
+        .children()
+        .any(|node| node.kind() == JsSyntaxKind::JS_FUNCTION_BODY));
+}
+
+#[test]
+fn flow_only_type_syntax() {
+    let code = r#"function isString(x: mixed): boolean %checks { return typeof x === "string"; }
+function isNumber(x): %checks { return typeof x === "number"; }
+let a: ?string = null;
+type Pair<T> = [T, T];"#;
+
+    let flow = parse(code, JsFileSource::flow(), JsParserOptions::default());
+    let syntax = flow.syntax();
+    assert_eq!(syntax.to_string(), code);
+
+    let bogus: Vec<_> = syntax
+        .descendants()
+        .filter(|node| node.kind().is_bogus())
+        .map(|node| (node.kind(), node.text_trimmed().to_string()))
+        .collect();
+    assert_eq!(
+        bogus,
+        [
+            (JsSyntaxKind::TS_BOGUS_TYPE, "boolean %checks".to_string()),
+            (JsSyntaxKind::TS_BOGUS_TYPE, "%checks".to_string()),
+            (JsSyntaxKind::TS_BOGUS_TYPE, "?string".to_string()),
+        ]
+    );
+
+    assert_eq!(flow.diagnostics().len(), 3);
+    for diagnostic in flow.diagnostics() {
+        assert!(format!("{diagnostic:?}").contains("Flow"));
+    }
+
+    let ts = parse(code, JsFileSource::ts(), JsParserOptions::default());
+    assert!(ts
+        .diagnostics()
+        .iter()
+        .all(|diagnostic| !format!("{diagnostic:?}").contains("Flow")));
+}
+
+#[test]
+fn module_characteristics() {
+    let parse = parse_module(
+        "#!/usr/bin/env node\n'use strict';\n\"use client\";\nrun();",
+        JsParserOptions::default(),
+    );
+    assert_eq!(
+        parse
+            .hashbang()
+            .map(|token| token.text_trimmed().to_string()),
+        Some("#!/usr/bin/env node".to_string())
+    );
+    let directives: Vec<_> = parse
+        .directives()
+        .iter()
+        .map(|directive| directive.inner_string_text().unwrap().to_string())
+        .collect();
+    assert_eq!(directives, ["use strict", "use client"]);
+    assert!(!parse.has_top_level_await());
+
+    for code in [
+        "await run();",
+        "for await (const chunk of stream) {}",
+        "await using handle = open();",
+        "class A extends (await load()) {}",
+        "const a = { [await key()]: 1 };",
+        "if (ready) { const b = () => 1; await b(); }",
+        // Parsed after the parser rewinds from trying an arrow function
+        "(await a, b);",
+    ] {
+        let parse = parse_module(code, JsParserOptions::default());
+        assert!(!parse.has_errors(), "{code}: {:?}", parse.diagnostics());
+        assert!(parse.has_top_level_await(), "{code}");
+    }
+
+    for code in [
+        "async function f() { await run(); }",
+        "const f = async () => await run();",
+        "const f = async (a = 1) => { for await (const b of a) {} };",
+        "class A { async m() { await run(); } static { run(); } }",
+        "const o = { async m() { await using h = open(); } };",
+    ] {
+        let parse = parse_module(code, JsParserOptions::default());
+        assert!(!parse.has_errors(), "{code}: {:?}", parse.diagnostics());
+        assert!(!parse.has_top_level_await(), "{code}");
+    }
+
+    let script = parse_script("var await = 1; await;", JsParserOptions::default());
+    assert!(!script.has_errors(), "{:?}", script.diagnostics());
+    assert!(!script.has_top_level_await());
+    assert!(script.hashbang().is_none());
+    assert!(script.directives().is_empty());
+}
+
+#[test]
+fn reparse_keeps_top_level_await() {
+    let code = "let a = 1;\nawait run();\n";
+    let parse = parse_module(code, JsParserOptions::default());
+    assert!(parse.has_top_level_await());
+
+    for (needle, len, replacement, expected) in [
+        ("1;", 2, "2;", true),
+        ("await run();", 12, "run();", false),
+        ("let a = 1;", 10, "let a = await load();", true),
+    ] {
+        let start = TextSize::from(code.find(needle).unwrap() as u32);
+        let edit = TextRange::at(start, TextSize::from(len));
+        let reparsed = parse.reparse(edit, replacement, JsParserOptions::default());
+        assert_eq!(reparsed.has_top_level_await(), expected, "{replacement}");
+    }
+
+    let parse = parse_module("let a = 1;\nrun();\n", JsParserOptions::default());
+    let edit = TextRange::at(TextSize::from(11), TextSize::from(6));
+    let reparsed = parse.reparse(edit, "await run();", JsParserOptions::default());
+    assert!(reparsed.has_top_level_await());
+}
+
+#[test]
+fn parse_expression_snippets() {
+    let options = JsParserOptions::default();
+
+    for code in [
+        "a ? b : c",
+        "{ a: 1 }",
+        " /* c */ await f(1, 2) ",
+        "<div />",
+    ] {
+        let parse = parse_expression(code, JsFileSource::jsx(), options.clone());
+        assert!(!parse.has_errors(), "{code}: {:?}", parse.diagnostics());
+        assert!(!has_bogus_nodes_or_empty_slots(&parse.syntax()), "{code}");
+        assert_eq!(parse.syntax().text(), code);
+    }
+
+    let ts = parse_expression("value as string", JsFileSource::ts(), options.clone());
+    assert!(!ts.has_errors(), "{:?}", ts.diagnostics());
+    let js = parse_expression(
+        "value as string",
+        JsFileSource::js_module(),
+        options.clone(),
+    );
+    assert!(js.has_errors());
+
+    let trailing = parse_expression("a b; c", JsFileSource::js_module(), options.clone());
+    assert_eq!(trailing.diagnostics().len(), 1);
+    let expression = trailing.tree().expression().unwrap();
+    assert_eq!(
+        expression.syntax().kind(),
+        JsSyntaxKind::JS_BOGUS_EXPRESSION
+    );
+    assert_eq!(expression.syntax().text(), "a b; c");
+
+    let empty = parse_expression("  ", JsFileSource::js_module(), options.clone());
+    assert!(empty.has_errors());
+    assert!(empty.tree().expression().is_err());
+
+    let parse = parse_expression("a + 1", JsFileSource::js_module(), options.clone());
+    let reparsed = parse.reparse(
+        TextRange::new(TextSize::from(4), TextSize::from(5)),
+        "f(2)",
+        options,
+    );
+    assert!(!reparsed.has_errors());
+    assert_eq!(reparsed.tree().syntax().text(), "a + f(2)");
+}
+
+#[test]
+fn parse_type_snippets() {
+    let options = JsParserOptions::default();
+
+    for code in [
+        "string | number",
+        " /* c */ Array<{ a: 1 }> ",
+        "(a: string) => void",
+        "T extends U ? keyof T : never",
+        "typeof import('./a')",
+    ] {
+        let parse = parse_type(code, JsFileSource::ts(), options.clone());
+        assert!(!parse.has_errors(), "{code}: {:?}", parse.diagnostics());
+        assert!(!has_bogus_nodes_or_empty_slots(&parse.syntax()), "{code}");
+        assert_eq!(parse.syntax().text(), code);
+    }
+
+    let flow = parse_type("string | null", JsFileSource::flow(), options.clone());
+    assert!(!flow.has_errors(), "{:?}", flow.diagnostics());
+    let js = parse_type("string", JsFileSource::js_module(), options.clone());
+    assert!(js.has_errors());
+
+    let trailing = parse_type("string; number", JsFileSource::ts(), options.clone());
+    assert_eq!(trailing.diagnostics().len(), 1);
+    let ty = trailing.tree().ty().unwrap();
+    assert_eq!(ty.syntax().kind(), JsSyntaxKind::TS_BOGUS_TYPE);
+    assert_eq!(ty.syntax().text(), "string; number");
+
+    let empty = parse_type("  ", JsFileSource::ts(), options.clone());
+    assert!(empty.has_errors());
+    assert!(empty.tree().ty().is_err());
+
+    let parse = parse_type("A | B", JsFileSource::ts(), options.clone());
+    let reparsed = parse.reparse(
+        TextRange::new(TextSize::from(4), TextSize::from(5)),
+        "C[]",
+        options,
+    );
+    assert!(!reparsed.has_errors());
+    assert_eq!(reparsed.syntax().kind(), JsSyntaxKind::TS_TYPE_SNIPPED);
+    assert_eq!(reparsed.tree().syntax().text(), "A | C[]");
+}
+
+#[test]
+fn parse_many_mixed_batch() {
+    let files = parse_many(
+        vec![
+            (PathBuf::from("src/a.ts"), "let a: number = 1;".to_string()),
+            (PathBuf::from("src/b.jsx"), "const b = <div />;".to_string()),
+            (
+                PathBuf::from("src/c.cjs"),
+                "module.exports = {};".to_string(),
+            ),
+            (PathBuf::from("README.md"), "# Readme".to_string()),
+        ],
+        &JsParserOptions::default(),
+    );
+
+    let source_types: Vec<_> = files
+        .parsed
+        .iter()
+        .map(|(path, parse)| {
+            assert!(parse.diagnostics().is_empty(), "{path:?}");
+            (path.as_path(), parse.source_type())
+        })
+        .collect();
+    assert_eq!(
+        source_types,
+        [
+            (
+                Path::new("src/a.ts"),
+                Some(JsFileSource::ts().with_version(LanguageVersion::ESNext))
+            ),
+            (
+                Path::new("src/b.jsx"),
+                Some(JsFileSource::jsx().with_version(LanguageVersion::ESNext))
+            ),
+            (
+                Path::new("src/c.cjs"),
+                Some(JsFileSource::js_script().with_version(LanguageVersion::ESNext))
+            ),
+        ]
+    );
+
+    assert_eq!(files.errors.len(), 1);
+    assert_eq!(files.errors[0].0, Path::new("README.md"));
+    assert!(matches!(files.errors[0].1, ParseManyError::FileSource(_)));
+}
+
+#[test]
+fn string_literal_diagnostic_ranges() {
+    fn diagnostic_span(parse: &Parse<JsScript>, message: &str) -> Option<TextRange> {
+        parse
+            .diagnostics()
+            .iter()
+            .find(|diagnostic| PrintDescription(*diagnostic).to_string() == message)
+            .and_then(|diagnostic| diagnostic.location().span)
+    }
+
+    let unterminated = parse_script(r#"let a = "abc"#, JsParserOptions::default());
+    assert_eq!(
+        diagnostic_span(&unterminated, "unterminated string literal"),
+        Some(TextRange::new(12.into(), 12.into()))
+    );
+
+    let invalid_escape = parse_script(r#"let a = "\u{ZZ}";"#, JsParserOptions::default());
+    assert_eq!(
+        diagnostic_span(
+            &invalid_escape,
+            "expected hex digits for a unicode code point escape, but encountered an invalid character"
+        ),
+        Some(TextRange::new(12.into(), 13.into()))
+    );
+
+    let strict = parse_script(r#""use strict"; let a = "\8";"#, JsParserOptions::default());
+    assert_eq!(strict.diagnostics().len(), 1);
+    assert_eq!(
+        diagnostic_span(
+            &strict,
+            r"The escape sequences \8 and \9 are not allowed in strict mode."
+        ),
+        Some(TextRange::new(23.into(), 25.into()))
+    );
+
+    let sloppy = parse_script(r#"let a = "\8";"#, JsParserOptions::default());
+    assert!(sloppy.diagnostics().is_empty());
+
+    let escaped_backslash = parse_script(
+        r#""use strict"; let a = "\\8";"#,
+        JsParserOptions::default(),
+    );
+    assert!(escaped_backslash.diagnostics().is_empty());
+}
+
+#[test]
+fn strict_mode_origin_labels() {
+    #[derive(Default)]
+    struct Frames(Vec<Option<TextRange>>);
+
+    impl Visit for Frames {
+        fn record_frame(&mut self, location: Location<'_>) -> std::io::Result<()> {
+            self.0.push(location.span);
+            Ok(())
+        }
+    }
+
+    let source = "with (a) {}";
+    let module = parse_module(source, JsParserOptions::default());
+    assert_eq!(module.diagnostics().len(), 1);
+    let mut frames = Frames::default();
+    module.diagnostics()[0].advices(&mut frames).unwrap();
+    assert!(frames.0.is_empty());
+    assert!(module
+        .render_diagnostics("example.js", source)
+        .contains("Modules are always in strict mode."));
+
+    let source = "'use strict';\nwith (a) {}";
+    let script = parse_script(source, JsParserOptions::default());
+    assert_eq!(script.diagnostics().len(), 1);
+    let mut frames = Frames::default();
+    script.diagnostics()[0].advices(&mut frames).unwrap();
+    assert_eq!(frames.0, [Some(TextRange::new(0.into(), 12.into()))]);
+    assert!(script
+        .render_diagnostics("example.js", source)
+        .contains("Strict mode is enabled by this directive."));
+}
+
+#[test]
+fn unknown_module_kind_is_resolved_while_parsing() {
+    assert_eq!(ModuleKind::default(), ModuleKind::Module);
+    assert!(!ModuleKind::Unknown.is_module());
+    assert!(!ModuleKind::Unknown.is_script());
+
+    let source_type = JsFileSource::js_module().with_module_kind(ModuleKind::Unknown);
+
+    let module = parse("export {};", source_type, JsParserOptions::default());
+    assert_eq!(module.module_kind(), ModuleKind::Module);
+    assert_eq!(
+        module
+            .source_type()
+            .map(|source_type| source_type.module_kind()),
+        Some(ModuleKind::Module)
+    );
+
+    let script = parse("let a = 1;\na++;", source_type, JsParserOptions::default());
+    assert_eq!(script.module_kind(), ModuleKind::Script);
+    assert_eq!(
+        script
+            .source_type()
+            .map(|source_type| source_type.module_kind()),
+        Some(ModuleKind::Script)
+    );
+}
+
+#[test]
+fn parse_validate_stops_at_first_error() {
+    let source = "let a = 1;\nfunction f() { let b = ; }\nlet c = ;\nlet d = ;";
+
+    assert_eq!(
+        parse_module(source, JsParserOptions::default())
+            .diagnostics()
+            .len(),
+        3
+    );
+
+    let diagnostics = parse_validate(source, JsFileSource::js_module()).unwrap_err();
+    assert!(!diagnostics.is_empty());
+    assert_eq!(
+        diagnostics[0].location().span,
+        Some(TextRange::new(34.into(), 35.into()))
+    );
+    assert!(diagnostics
+        .iter()
+        .all(|diagnostic| diagnostic.location().span.unwrap().start() < TextSize::from(38)));
+
+    assert!(parse_validate("let a = 1;\nlet b = a;", JsFileSource::js_module()).is_ok());
+}
+
+#[test]
+fn parse_from_lexer_matches_parse() {
+    let source = "let a = /b/g;\nlet c = `d${a}`;\nlet e = ;";
+    let mut lexer = BufferedLexer::new(JsLexer::from_str(source));
+    let last = lexer.lookahead_iter().last().map(|token| token.kind());
+    assert_eq!(last, Some(JsSyntaxKind::EOF));
+
+    let from_lexer =
+        parse_from_lexer(lexer, JsFileSource::js_module(), JsParserOptions::default()).unwrap();
+    let module = parse_module(source, JsParserOptions::default());
+
+    assert_eq!(
+        format!("{:#?}", from_lexer.syntax()),
+        format!("{:#?}", module.syntax())
+    );
+    assert_eq!(from_lexer.diagnostics().len(), module.diagnostics().len());
+
+    let source = "let a = <div>{b as C}</div>;";
+    let lexer = BufferedLexer::new(JsLexer::from_str(source));
+    let from_lexer =
+        parse_from_lexer(lexer, JsFileSource::tsx(), JsParserOptions::default()).unwrap();
+    let parsed = parse(source, JsFileSource::tsx(), JsParserOptions::default());
+
+    assert_eq!(
+        format!("{:#?}", from_lexer.syntax()),
+        format!("{:#?}", parsed.syntax())
+    );
+    assert_eq!(from_lexer.source_type(), Some(JsFileSource::tsx()));
+}
+
+#[test]
+fn parse_from_lexer_validates_lexer() {
+    let source = "let a = 1;";
+    let mut lexer = BufferedLexer::new(JsLexer::from_str(source));
+    lexer.next_token(JsLexContext::default());
+    assert!(
+        parse_from_lexer(lexer, JsFileSource::js_module(), JsParserOptions::default()).is_err()
+    );
+
+    let source = "<div>a</div>";
+    let lex_as_jsx = || {
+        let mut lexer = BufferedLexer::new(JsLexer::from_str(source));
+        let start = lexer.checkpoint();
+        lexer.next_token(JsLexContext::default());
+        lexer.next_token(JsLexContext::JsxChild);
+        lexer.rewind(start);
+        lexer
+    };
+    assert!(parse_from_lexer(
+        lex_as_jsx(),
+        JsFileSource::js_module(),
+        JsParserOptions::default()
+    )
+    .is_err());
+    assert!(parse_from_lexer(
+        lex_as_jsx(),
+        JsFileSource::jsx(),
+        JsParserOptions::default()
+    )
+    .unwrap()
+    .diagnostics()
+    .is_empty());
+
+    let source = "f<<T>(a: T) => void>();";
+    let mut lexer = BufferedLexer::new(JsLexer::from_str(source));
+    let start = lexer.checkpoint();
+    lexer.next_token(JsLexContext::default());
+    lexer.next_token(JsLexContext::default());
+    lexer.re_lex(JsReLexContext::TypeArgumentLessThan);
+    lexer.rewind(start);
+    assert!(parse_from_lexer(lexer, JsFileSource::jsx(), JsParserOptions::default()).is_err());
+}
+
+#[test]
+fn lone_surrogates() {
+    let options = JsParserOptions::default().with_check_lone_surrogates();
+
+    let root = parse_module(
+        r#"let a = "\uD83D\uDE00" + `\uD83D\uDE00`;"#,
+        options.clone(),
+    );
+    assert!(root.diagnostics().is_empty());
+
+    let code = r#"let a = "b\uD800c";"#;
+    let root = parse_module(code, options.clone());
+    assert!(!root.has_errors());
+    assert_eq!(root.warnings().count(), 1);
+    let range = root.warnings().next().unwrap().location().span.unwrap();
+    assert_eq!(&code[range], r"\uD800");
+
+    let code = r#"let a = `\\uDC00 ${b} \uDC00`;"#;
+    let root = parse_module(code, options);
+    assert_eq!(root.warnings().count(), 1);
+    let range = root.warnings().next().unwrap().location().span.unwrap();
+    assert_eq!(range, TextRange::new(22.into(), 28.into()));
+
+    let root = parse_module(r#"let a = "\uD800";"#, JsParserOptions::default());
+    assert!(root.diagnostics().is_empty());
+}
+
+#[test]
+fn max_nesting_depth() {
+    let depth = 100_000;
+    let code = format!("{}a{};", "(".repeat(depth), ")".repeat(depth));
+    let options = JsParserOptions::default().with_max_nesting_depth(64);
+
+    let parse = parse_module(&code, options.clone());
+    assert!(parse.max_depth_reached());
+    assert_eq!(parse.syntax().text(), code.as_str());
+    assert_eq!(parse.diagnostics().len(), 1);
+    assert_eq!(
+        PrintDescription(&parse.diagnostics()[0]).to_string(),
+        "This code is nested too deeply."
+    );
+    assert!(parse
+        .syntax()
+        .descendants()
+        .any(|node| node.kind() == JsSyntaxKind::JS_BOGUS_EXPRESSION));
+    assert!(parse.tree_depth() < 100);
+
+    let code = format!("{}{}", "{".repeat(depth), "}".repeat(depth));
+    let parse = parse_module(&code, options.clone());
+    assert!(parse.max_depth_reached());
+    assert_eq!(parse.syntax().text(), code.as_str());
+
+    let parse = parse_module("let a = [[1], (2)];", options);
+    assert!(!parse.max_depth_reached());
+    assert!(parse.diagnostics().is_empty());
+}
+
+#[test]
+fn jsx_tokenization_follows_the_variant() {
+    let token_kinds = |parse: &Parse<AnyJsRoot>| {
+        parse
+            .syntax()
+            .descendants_tokens(Direction::Next)
+            .map(|token| token.kind())
+            .collect::<Vec<_>>()
+    };
+
+    // The lexer has no JSX state: the parser picks the lex context from the variant, and a `<`
+    // that follows an expression is always a comparison
+    for source_type in [JsFileSource::js_module(), JsFileSource::jsx()] {
+        let parse = parse("a < b > c;", source_type, JsParserOptions::default());
+        assert!(parse.diagnostics().is_empty(), "{source_type:?}");
+        assert_eq!(
+            token_kinds(&parse),
+            [
+                JsSyntaxKind::IDENT,
+                JsSyntaxKind::L_ANGLE,
+                JsSyntaxKind::IDENT,
+                JsSyntaxKind::R_ANGLE,
+                JsSyntaxKind::IDENT,
+                JsSyntaxKind::SEMICOLON,
+                JsSyntaxKind::EOF,
+            ]
+        );
+    }
+
+    // A `<` that starts an expression starts an element only in the JSX variant
+    let jsx = parse("<b>c</b>;", JsFileSource::tsx(), JsParserOptions::default());
+    assert!(jsx.diagnostics().is_empty());
+    assert!(token_kinds(&jsx).contains(&JsSyntaxKind::JSX_TEXT_LITERAL));
+
+    let ts = parse("<b>c;", JsFileSource::ts(), JsParserOptions::default());
+    assert!(ts.diagnostics().is_empty());
+    assert!(!token_kinds(&ts).contains(&JsSyntaxKind::JSX_TEXT_LITERAL));
+    assert!(ts
+        .syntax()
+        .descendants()
+        .any(|node| node.kind() == JsSyntaxKind::TS_TYPE_ASSERTION_EXPRESSION));
+}
+
+#[test]
+fn return_outside_function() {
+    let code = "if (a) return;\nreturn b;";
+
+    let parse = parse_script(code, JsParserOptions::default());
+    assert_eq!(parse.diagnostics().len(), 2);
+
+    let options = JsParserOptions::default().with_allow_return_outside_function();
+    let parse = parse_script(code, options.clone());
+    assert!(parse.diagnostics().is_empty());
+    assert_eq!(
+        parse
+            .syntax()
+            .descendants()
+            .filter(|node| node.kind() == JsSyntaxKind::JS_RETURN_STATEMENT)
+            .count(),
+        2
+    );
+
+    let parse = parse_module("return;", options);
+    assert!(parse.diagnostics().is_empty());
+}
+
+#[test]
+fn estree_conversion() {
+    let code = "import a, { b as c } from \"m\";\nexport const d = a?.b(c), e = `x${d}`;\n";
+    let parse = parse(code, JsFileSource::js_module(), JsParserOptions::default());
+    let program = to_estree(&parse.tree()).unwrap();
+
+    assert_eq!(program["type"], "Program");
+    assert_eq!(program["sourceType"], "module");
+    assert_eq!(program["range"], json!([0, code.len()]));
+
+    let import = &program["body"][0];
+    assert_eq!(import["type"], "ImportDeclaration");
+    assert_eq!(import["source"]["value"], "m");
+    assert_eq!(import["specifiers"][0]["type"], "ImportDefaultSpecifier");
+    assert_eq!(import["specifiers"][1]["imported"]["name"], "b");
+    assert_eq!(import["specifiers"][1]["local"]["name"], "c");
+
+    let export = &program["body"][1];
+    assert_eq!(export["type"], "ExportNamedDeclaration");
+    assert_eq!(export["loc"]["start"], json!({ "line": 2, "column": 0 }));
+    let declarations = &export["declaration"]["declarations"];
+    assert_eq!(export["declaration"]["kind"], "const");
+
+    let chain = &declarations[0]["init"];
+    assert_eq!(chain["type"], "ChainExpression");
+    assert_eq!(chain["expression"]["type"], "CallExpression");
+    assert_eq!(chain["expression"]["optional"], false);
+    assert_eq!(chain["expression"]["callee"]["optional"], true);
+
+    let template = &declarations[1]["init"];
+    assert_eq!(template["type"], "TemplateLiteral");
+    assert_eq!(template["quasis"][0]["value"]["cooked"], "x");
+    assert_eq!(template["quasis"][1]["value"]["cooked"], "");
+    assert_eq!(template["quasis"][1]["tail"], true);
+    assert_eq!(template["expressions"][0]["name"], "d");
+}
+
+#[test]
+fn estree_literals_and_locations() {
+    let code = "'\\u{1F600}\\x41';\n(1, 2, 3);\n\"😀\"; 0x10;\n";
+    let parse = parse(code, JsFileSource::js_script(), JsParserOptions::default());
+    let program = to_estree(&parse.tree()).unwrap();
+
+    assert_eq!(program["sourceType"], "script");
+    // A string at the start of a script is a directive
+    assert_eq!(program["body"][0]["directive"], "\\u{1F600}\\x41");
+    assert_eq!(program["body"][0]["expression"]["value"], "😀A");
+
+    let sequence = &program["body"][1]["expression"];
+    assert_eq!(sequence["type"], "SequenceExpression");
+    assert_eq!(sequence["expressions"].as_array().unwrap().len(), 3);
+    // Parentheses aren't part of the ESTree
+    assert_eq!(sequence["loc"]["start"], json!({ "line": 2, "column": 1 }));
+
+    // Columns and offsets count UTF-16 code units
+    let number = &program["body"][3]["expression"];
+    assert_eq!(number["value"], 16);
+    assert_eq!(number["loc"]["start"], json!({ "line": 3, "column": 6 }));
+    let line_start = code.find("\"😀\"").unwrap();
+    assert_eq!(number["start"], line_start + 6);
+}
+
+#[test]
+fn estree_unsupported_syntax() {
+    let typescript = parse(
+        "let a: number = 1;",
+        JsFileSource::ts(),
+        JsParserOptions::default(),
+    );
+    assert!(matches!(
+        to_estree(&typescript.tree()),
+        Err(EstreeError::Unsupported {
+            kind: JsSyntaxKind::TS_TYPE_ANNOTATION,
+            ..
+        })
+    ));
+
+    let invalid = parse(
+        "let = ;",
+        JsFileSource::js_module(),
+        JsParserOptions::default(),
+    );
+    assert!(to_estree(&invalid.tree()).is_err());
+}
+
 #[ignore]
 #[test]
 pub fn quick_test() {
@@ -0,0 +1,101 @@
+//! A [TreeSink] that records the tokens of the source instead of building a syntax tree.
+
+use crate::lexer::{JsLexContext, JsLexer};
+use crate::prelude::*;
+use biome_js_syntax::{JsSyntaxKind, TextRange, TextSize};
+use biome_parser::lexer::Lexer;
+use biome_parser::tree_sink::TreeSink;
+use biome_rowan::SyntaxKind;
+
+/// A [TreeSink] that records the kind and range of every token in source order, and the
+/// diagnostics of the parser. Use it with [crate::parse_with_visitor].
+///
+/// The ranges don't include the trivia of the tokens. Tokens that the parser skipped while
+/// recovering from an error are trivia too, but the range of the token that follows them
+/// starts at the first skipped token.
+///
+/// ```
+/// use biome_js_parser::{parse_with_visitor, JsParserOptions, TokenSinkAdapter};
+/// use biome_js_syntax::{JsFileSource, JsSyntaxKind, T};
+///
+/// let source = "let a";
+/// let mut sink = TokenSinkAdapter::new(source);
+/// parse_with_visitor(source, JsFileSource::js_module(), JsParserOptions::default(), &mut sink);
+///
+/// let kinds: Vec<_> = sink.tokens().iter().map(|(kind, _)| *kind).collect();
+/// assert_eq!(kinds, [T![let], JsSyntaxKind::IDENT]);
+/// ```
+#[derive(Debug)]
+pub struct TokenSinkAdapter<'source> {
+    text: &'source str,
+    /// The end of the previous token
+    offset: TextSize,
+    tokens: Vec<(JsSyntaxKind, TextRange)>,
+    diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl<'source> TokenSinkAdapter<'source> {
+    /// Creates a sink for the tokens of `text`, which must be the parsed source.
+    pub fn new(text: &'source str) -> Self {
+        Self {
+            text,
+            offset: TextSize::from(0),
+            tokens: Vec::new(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// The kind and range of the tokens recorded so far.
+    pub fn tokens(&self) -> &[(JsSyntaxKind, TextRange)] {
+        &self.tokens
+    }
+
+    /// The diagnostics of the parser.
+    pub fn diagnostics(&self) -> &[ParseDiagnostic] {
+        &self.diagnostics
+    }
+
+    /// Returns the recorded tokens and the diagnostics of the parser.
+    pub fn finish(self) -> (Vec<(JsSyntaxKind, TextRange)>, Vec<ParseDiagnostic>) {
+        (self.tokens, self.diagnostics)
+    }
+
+    /// Returns the start of the token of `kind` that ends at `end`, skipping the trivia
+    /// between the previous token and this token.
+    fn token_start(&self, kind: JsSyntaxKind, end: TextSize) -> TextSize {
+        // Template chunks and JSX text directly follow the previous token and may start with
+        // whitespace, which the lexer would take for trivia.
+        if matches!(
+            kind,
+            JsSyntaxKind::TEMPLATE_CHUNK | JsSyntaxKind::JSX_TEXT_LITERAL
+        ) {
+            return self.offset;
+        }
+
+        let mut lexer = JsLexer::from_str(&self.text[TextRange::new(self.offset, end)]);
+
+        loop {
+            if !lexer.next_token(JsLexContext::Regular).is_trivia() {
+                return self.offset + lexer.current_start();
+            }
+        }
+    }
+}
+
+impl TreeSink for TokenSinkAdapter<'_> {
+    type Kind = JsSyntaxKind;
+
+    fn token(&mut self, kind: JsSyntaxKind, end: TextSize) {
+        let start = self.token_start(kind, end);
+        self.tokens.push((kind, TextRange::new(start, end)));
+        self.offset = end;
+    }
+
+    fn start_node(&mut self, _kind: JsSyntaxKind) {}
+
+    fn finish_node(&mut self) {}
+
+    fn errors(&mut self, errors: Vec<ParseDiagnostic>) {
+        self.diagnostics = errors;
+    }
+}
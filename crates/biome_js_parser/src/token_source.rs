@@ -27,9 +27,20 @@ impl<'l> JsTokenSource<'l> {
 
     /// Creates a new token source for the given string
     pub fn from_str(source: &'l str) -> JsTokenSource<'l> {
-        let lexer = JsLexer::from_str(source);
-        let buffered = BufferedLexer::new(lexer);
-        let mut source = JsTokenSource::new(buffered);
+        Self::from_lexer(JsLexer::from_str(source))
+    }
+
+    /// Creates a new token source from an already configured lexer
+    pub(crate) fn from_lexer(lexer: JsLexer<'l>) -> JsTokenSource<'l> {
+        Self::from_buffered_lexer(BufferedLexer::new(lexer))
+    }
+
+    /// Creates a new token source from a buffered lexer that hasn't returned any token yet.
+    /// Tokens that the lexer already lexed ahead are taken from its lookahead buffer.
+    pub(crate) fn from_buffered_lexer(
+        lexer: BufferedLexer<JsSyntaxKind, JsLexer<'l>>,
+    ) -> JsTokenSource<'l> {
+        let mut source = JsTokenSource::new(lexer);
 
         source.next_non_trivia_token(JsLexContext::default(), true);
         source
@@ -0,0 +1,182 @@
+//! Syntax highlighting of JavaScript source code and syntax nodes.
+
+use crate::{parse, JsParserOptions};
+use biome_js_syntax::{JsFileSource, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken};
+use biome_rowan::{Direction, SyntaxKind};
+
+/// The category of a highlighted piece of source code.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum HighlightCategory {
+    /// Contextual and non-contextual keywords, e.g. `const` or `async`.
+    Keyword,
+    /// String literals and template chunks, including their quotes and backticks.
+    String,
+    /// Number and bigint literals.
+    Number,
+    /// Regular expression literals.
+    Regex,
+    /// Single and multi-line comments.
+    Comment,
+    /// Operators and punctuation, e.g. `=` or `;`.
+    Punctuation,
+    /// Tokens of a syntax error, which the parser wrapped in a bogus node.
+    Bogus,
+}
+
+impl HighlightCategory {
+    /// A stable name of the category, e.g. for CSS classes.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            HighlightCategory::Keyword => "keyword",
+            HighlightCategory::String => "string",
+            HighlightCategory::Number => "number",
+            HighlightCategory::Regex => "regex",
+            HighlightCategory::Comment => "comment",
+            HighlightCategory::Punctuation => "punctuation",
+            HighlightCategory::Bogus => "bogus",
+        }
+    }
+
+    /// The ANSI escape sequence that starts text of this category.
+    const fn ansi_color(self) -> &'static str {
+        match self {
+            HighlightCategory::Keyword => "\x1b[35m",
+            HighlightCategory::String => "\x1b[32m",
+            HighlightCategory::Number => "\x1b[33m",
+            HighlightCategory::Regex => "\x1b[31m",
+            HighlightCategory::Comment => "\x1b[90m",
+            HighlightCategory::Punctuation => "\x1b[36m",
+            HighlightCategory::Bogus => "\x1b[4;31m",
+        }
+    }
+}
+
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Returns the highlight category of `token`, or `None` for identifiers and plain text.
+pub fn classify_token(token: &JsSyntaxToken) -> Option<HighlightCategory> {
+    if token.ancestors().any(|node| node.kind().is_bogus()) {
+        return Some(HighlightCategory::Bogus);
+    }
+
+    let kind = token.kind();
+    let category = match kind {
+        _ if kind.is_keyword() => HighlightCategory::Keyword,
+        JsSyntaxKind::JS_STRING_LITERAL
+        | JsSyntaxKind::JSX_STRING_LITERAL
+        | JsSyntaxKind::TEMPLATE_CHUNK
+        | JsSyntaxKind::BACKTICK => HighlightCategory::String,
+        JsSyntaxKind::JS_NUMBER_LITERAL | JsSyntaxKind::JS_BIGINT_LITERAL => {
+            HighlightCategory::Number
+        }
+        JsSyntaxKind::JS_REGEX_LITERAL => HighlightCategory::Regex,
+        _ if kind.is_punct() => HighlightCategory::Punctuation,
+        JsSyntaxKind::DOLLAR_CURLY | JsSyntaxKind::HASH => HighlightCategory::Punctuation,
+        _ => return None,
+    };
+
+    Some(category)
+}
+
+/// Calls `f` with every piece of the text of `node`, in source order, and its category.
+///
+/// The pieces are the trimmed text of each token and each of their trivia pieces.
+fn visit_highlights(node: &JsSyntaxNode, mut f: impl FnMut(&str, Option<HighlightCategory>)) {
+    for token in node.descendants_tokens(Direction::Next) {
+        let category = classify_token(&token);
+
+        for piece in token.leading_trivia().pieces() {
+            f(
+                piece.text(),
+                piece.is_comments().then_some(HighlightCategory::Comment),
+            );
+        }
+
+        f(token.text_trimmed(), category);
+
+        for piece in token.trailing_trivia().pieces() {
+            f(
+                piece.text(),
+                piece.is_comments().then_some(HighlightCategory::Comment),
+            );
+        }
+    }
+}
+
+/// Returns the text of `node` with ANSI color escape sequences around keywords, literals,
+/// comments, punctuation, and syntax errors.
+///
+/// ```
+/// use biome_js_parser::{parse_module, JsParserOptions};
+/// use biome_js_parser::util::highlight;
+///
+/// let parse = parse_module("let a = 1;", JsParserOptions::default());
+/// assert!(highlight(&parse.syntax()).contains("\x1b[33m1\x1b[0m"));
+/// ```
+pub fn highlight(node: &JsSyntaxNode) -> String {
+    let mut highlighted = String::new();
+
+    visit_highlights(node, |text, category| match category {
+        Some(category) if !text.is_empty() => {
+            highlighted.push_str(category.ansi_color());
+            highlighted.push_str(text);
+            highlighted.push_str(ANSI_RESET);
+        }
+        _ => highlighted.push_str(text),
+    });
+
+    highlighted
+}
+
+/// Parses `text` and returns it with ANSI color escape sequences, see [highlight].
+pub fn highlight_source(text: &str, source_type: JsFileSource) -> String {
+    let parse = parse(text, source_type, JsParserOptions::default());
+    highlight(&parse.syntax())
+}
+
+/// Parses `text` and returns it as HTML, with each highlighted piece wrapped in a
+/// `<span class="...">` named after its [HighlightCategory].
+///
+/// The text is HTML-escaped. Spans never nest: a multi-line comment or template chunk is
+/// wrapped in a single span.
+///
+/// ```
+/// use biome_js_parser::util::highlight_html;
+/// use biome_js_syntax::JsFileSource;
+///
+/// let html = highlight_html("a < 1", JsFileSource::js_module());
+/// assert_eq!(
+///     html,
+///     r#"a <span class="punctuation">&lt;</span> <span class="number">1</span>"#
+/// );
+/// ```
+pub fn highlight_html(text: &str, source_type: JsFileSource) -> String {
+    let parse = parse(text, source_type, JsParserOptions::default());
+    let mut highlighted = String::new();
+
+    visit_highlights(&parse.syntax(), |text, category| match category {
+        Some(category) if !text.is_empty() => {
+            highlighted.push_str("<span class=\"");
+            highlighted.push_str(category.as_str());
+            highlighted.push_str("\">");
+            push_html_escaped(&mut highlighted, text);
+            highlighted.push_str("</span>");
+        }
+        _ => push_html_escaped(&mut highlighted, text),
+    });
+
+    highlighted
+}
+
+fn push_html_escaped(buffer: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '<' => buffer.push_str("&lt;"),
+            '>' => buffer.push_str("&gt;"),
+            '&' => buffer.push_str("&amp;"),
+            '"' => buffer.push_str("&quot;"),
+            '\'' => buffer.push_str("&#39;"),
+            _ => buffer.push(c),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+class A extends B {
+  #x = 1;
+  static y;
+  static #z() {}
+  get #w() { return this.#x; }
+  static { #x in this; }
+  constructor() { super(); }
+}
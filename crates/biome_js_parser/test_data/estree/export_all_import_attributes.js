@@ -0,0 +1,4 @@
+export * as ns from "a";
+export * from "b";
+import c from "./c.json" with { type: "json" };
+export { d } from "./d.json" with { type: "json" };
@@ -0,0 +1,4 @@
+for await (const x of y) {}
+async function f() {
+  for await (z of w);
+}
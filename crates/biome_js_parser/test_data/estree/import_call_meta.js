@@ -0,0 +1,3 @@
+import("./a.js");
+import("./b.json", { with: { type: "json" } });
+import.meta.url;
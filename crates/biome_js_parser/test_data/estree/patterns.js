@@ -0,0 +1,3 @@
+const { a, b: [c, , ...d] = [], ...e } = f;
+[g.h, { i = 1 }] = j;
+function k({ l } = {}, [m] = [], ...n) {}
@@ -0,0 +1,3 @@
+/a[/]b/giu;
+10n;
+0x1Fn;
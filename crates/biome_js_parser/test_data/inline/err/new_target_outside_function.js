@@ -0,0 +1,2 @@
+new.target;
+() => new.target;
@@ -0,0 +1,2 @@
+function f() { () => new.target; }
+class A { a = () => new.target; }
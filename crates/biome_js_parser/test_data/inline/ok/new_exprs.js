@@ -1,5 +1,5 @@
 new Foo()
 new foo;
-new.target
+function f() { new.target }
 new new new new Foo();
 new Foo(bar, baz, 6 + 6, foo[bar] + ((foo) => {}) * foo?.bar)
@@ -7,17 +7,17 @@ use crate::{
     AnyJsTemplateElement, JsArrayExpression, JsArrayHole, JsAssignmentExpression,
     JsBinaryExpression, JsCallArgumentList, JsCallArguments, JsCallExpression,
     JsComputedMemberAssignment, JsComputedMemberExpression, JsConditionalExpression,
-    JsDoWhileStatement, JsForStatement, JsIfStatement, JsLiteralMemberName, JsLogicalExpression,
-    JsNewExpression, JsNumberLiteralExpression, JsObjectExpression, JsPostUpdateExpression,
-    JsReferenceIdentifier, JsRegexLiteralExpression, JsStaticMemberExpression,
-    JsStringLiteralExpression, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken, JsTemplateChunkElement,
-    JsTemplateExpression, JsUnaryExpression, JsWhileStatement, OperatorPrecedence,
-    TsStringLiteralType, T,
+    JsDoWhileStatement, JsForStatement, JsIfStatement, JsInExpression, JsInstanceofExpression,
+    JsLiteralMemberName, JsLogicalExpression, JsNewExpression, JsNumberLiteralExpression,
+    JsObjectExpression, JsPostUpdateExpression, JsReferenceIdentifier, JsRegexLiteralExpression,
+    JsStaticMemberExpression, JsStringLiteralExpression, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken,
+    JsTemplateChunkElement, JsTemplateExpression, JsUnaryExpression, JsWhileStatement,
+    OperatorPrecedence, TsStringLiteralType, T,
 };
 use crate::{JsPreUpdateExpression, JsSyntaxKind::*};
 use biome_rowan::{
-    declare_node_union, AstNode, AstNodeList, AstSeparatedList, NodeOrToken, SyntaxNodeCast,
-    SyntaxResult, TextRange, TextSize, TokenText,
+    declare_node_union, AstNode, AstNodeList, AstSeparatedList, Direction, NodeOrToken,
+    SyntaxNodeCast, SyntaxResult, TextRange, TextSize, TokenText,
 };
 use core::iter;
 
@@ -1886,12 +1886,192 @@ pub fn is_in_boolean_context(node: &JsSyntaxNode) -> Option<bool> {
     }
 }
 
+/// Extension methods for untyped [JsSyntaxNode]s.
+pub trait SyntaxNodeExt {
+    /// Returns the operator token of a binary, logical, assignment, unary, or update
+    /// expression, and `None` for any other node.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use biome_js_factory::make::{js_binary_expression, js_identifier_expression, js_reference_identifier, ident, token};
+    /// use biome_js_syntax::{SyntaxNodeExt, T};
+    /// use biome_rowan::AstNode;
+    ///
+    /// let binary = js_binary_expression(
+    ///     js_identifier_expression(js_reference_identifier(ident("a"))).into(),
+    ///     token(T![+]),
+    ///     js_identifier_expression(js_reference_identifier(ident("b"))).into(),
+    /// );
+    ///
+    /// assert_eq!(binary.syntax().operator_token().unwrap().text_trimmed(), "+");
+    /// ```
+    fn operator_token(&self) -> Option<JsSyntaxToken>;
+
+    /// Returns the maximum nesting depth of the control statements and blocks in the body
+    /// of a function-like node, and `None` for any other node.
+    ///
+    /// `if`, `for`, `while`, `do`-`while`, `switch`, `try`, `with`, and standalone blocks
+    /// increase the depth by one. `else if` stays at the depth of its `if`. Nested
+    /// functions aren't counted.
+    ///
+    /// ## Example
+    ///
+    /// ```js
+    /// function f() {
+    ///     for (const x of xs) {
+    ///         if (x) {} // depth 2
+    ///     }
+    /// }
+    /// ```
+    fn max_nesting_depth(&self) -> Option<usize>;
+}
+
+impl SyntaxNodeExt for JsSyntaxNode {
+    fn operator_token(&self) -> Option<JsSyntaxToken> {
+        match self.kind() {
+            JS_BINARY_EXPRESSION => self.clone().cast::<JsBinaryExpression>()?.operator_token(),
+            JS_INSTANCEOF_EXPRESSION => self
+                .clone()
+                .cast::<JsInstanceofExpression>()?
+                .instanceof_token(),
+            JS_IN_EXPRESSION => self.clone().cast::<JsInExpression>()?.in_token(),
+            JS_LOGICAL_EXPRESSION => self.clone().cast::<JsLogicalExpression>()?.operator_token(),
+            JS_ASSIGNMENT_EXPRESSION => self
+                .clone()
+                .cast::<JsAssignmentExpression>()?
+                .operator_token(),
+            JS_UNARY_EXPRESSION => self.clone().cast::<JsUnaryExpression>()?.operator_token(),
+            JS_PRE_UPDATE_EXPRESSION => self
+                .clone()
+                .cast::<JsPreUpdateExpression>()?
+                .operator_token(),
+            JS_POST_UPDATE_EXPRESSION => self
+                .clone()
+                .cast::<JsPostUpdateExpression>()?
+                .operator_token(),
+            _ => return None,
+        }
+        .ok()
+    }
+
+    fn max_nesting_depth(&self) -> Option<usize> {
+        is_function_like(self.kind()).then(|| nesting_depth(self))
+    }
+}
+
+fn is_function_like(kind: JsSyntaxKind) -> bool {
+    matches!(
+        kind,
+        JS_FUNCTION_DECLARATION
+            | JS_FUNCTION_EXPRESSION
+            | JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+            | JS_ARROW_FUNCTION_EXPRESSION
+            | JS_METHOD_CLASS_MEMBER
+            | JS_METHOD_OBJECT_MEMBER
+            | JS_CONSTRUCTOR_CLASS_MEMBER
+            | JS_GETTER_CLASS_MEMBER
+            | JS_GETTER_OBJECT_MEMBER
+            | JS_SETTER_CLASS_MEMBER
+            | JS_SETTER_OBJECT_MEMBER
+            | JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER
+    )
+}
+
+fn nesting_depth(node: &JsSyntaxNode) -> usize {
+    node.children()
+        .filter(|child| !is_function_like(child.kind()))
+        .map(|child| {
+            let increases_depth = match child.kind() {
+                JS_IF_STATEMENT => node.kind() != JS_ELSE_CLAUSE,
+                JS_FOR_STATEMENT
+                | JS_FOR_IN_STATEMENT
+                | JS_FOR_OF_STATEMENT
+                | JS_WHILE_STATEMENT
+                | JS_DO_WHILE_STATEMENT
+                | JS_SWITCH_STATEMENT
+                | JS_TRY_STATEMENT
+                | JS_TRY_FINALLY_STATEMENT
+                | JS_WITH_STATEMENT => true,
+                // Blocks that are the body of a control statement are counted by their statement
+                JS_BLOCK_STATEMENT => node.kind() == JS_STATEMENT_LIST,
+                _ => false,
+            };
+
+            nesting_depth(&child) + usize::from(increases_depth)
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Extension methods for [JsSyntaxToken]s.
+pub trait SyntaxTokenExt {
+    /// Returns the bracket that pairs with this `(`, `{`, `[`, `)`, `}`, or `]` token,
+    /// and `None` for any other token or if the pair is missing.
+    ///
+    /// Both brackets of a pair are children of the same node, even in bogus nodes, so the
+    /// lookup only walks the siblings of the token.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use biome_js_parser::{parse_module, JsParserOptions};
+    /// use biome_js_syntax::{SyntaxTokenExt, T};
+    ///
+    /// let parse = parse_module("f(a, (b));", JsParserOptions::default());
+    /// let l_paren = parse
+    ///     .syntax()
+    ///     .descendants_tokens(biome_rowan::Direction::Next)
+    ///     .find(|token| token.kind() == T!['('])
+    ///     .unwrap();
+    ///
+    /// let r_paren = l_paren.matching_bracket().unwrap();
+    /// assert_eq!(r_paren.text_trimmed_range().start(), 8.into());
+    /// assert_eq!(r_paren.matching_bracket(), Some(l_paren));
+    /// ```
+    fn matching_bracket(&self) -> Option<JsSyntaxToken>;
+}
+
+impl SyntaxTokenExt for JsSyntaxToken {
+    fn matching_bracket(&self) -> Option<JsSyntaxToken> {
+        let (open, close, direction) = match self.kind() {
+            T!['('] => (T!['('], T![')'], Direction::Next),
+            T!['{'] => (T!['{'], T!['}'], Direction::Next),
+            T!['['] => (T!['['], T![']'], Direction::Next),
+            T![')'] => (T![')'], T!['('], Direction::Prev),
+            T!['}'] => (T!['}'], T!['{'], Direction::Prev),
+            T![']'] => (T![']'], T!['['], Direction::Prev),
+            _ => return None,
+        };
+
+        let mut depth = 0usize;
+        for token in self
+            .siblings_with_tokens(direction)
+            .filter_map(|element| element.into_token())
+        {
+            if token.kind() == open {
+                depth += 1;
+            } else if token.kind() == close {
+                if depth == 0 {
+                    return Some(token);
+                }
+                depth -= 1;
+            }
+        }
+
+        None
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use biome_js_factory::syntax::{JsCallExpression, JsTemplateExpression};
+    use biome_js_factory::syntax::{
+        JsCallExpression, JsFunctionBody, JsSyntaxKind, JsTemplateExpression, SyntaxNodeExt,
+        SyntaxTokenExt,
+    };
     use biome_js_parser::parse_module;
     use biome_js_parser::JsParserOptions;
-    use biome_rowan::AstNodeList;
+    use biome_rowan::{AstNode, AstNodeList, TextSize};
 
     fn extract_call_expression(src: &str) -> JsCallExpression {
         let result = parse_module(src, JsParserOptions::default());
@@ -2140,4 +2320,55 @@ mod test {
         let template = extract_template("fit.concurrent.skip.each``");
         assert!(template.is_test_each_pattern_callee());
     }
+
+    #[test]
+    fn operator_token() {
+        let result = parse_module("a + b; a && b;", JsParserOptions::default());
+        let operators: Vec<_> = result
+            .syntax()
+            .descendants()
+            .filter_map(|node| node.operator_token())
+            .map(|token| token.text_trimmed().to_string())
+            .collect();
+
+        assert_eq!(operators, ["+", "&&"]);
+    }
+
+    #[test]
+    fn max_nesting_depth() {
+        let result = parse_module(
+            "function f(xs) { for (const x of xs) { if (x) { g(() => { if (x) {} }); } } }",
+            JsParserOptions::default(),
+        );
+        let function = result
+            .syntax()
+            .descendants()
+            .find(|node| node.kind() == JsSyntaxKind::JS_FUNCTION_DECLARATION)
+            .unwrap();
+
+        assert_eq!(function.max_nesting_depth(), Some(2));
+        assert_eq!(result.syntax().max_nesting_depth(), None);
+    }
+
+    #[test]
+    fn matching_bracket_of_function_body() {
+        let code = "function f() { if (a) { b(); } }";
+        let result = parse_module(code, JsParserOptions::default());
+        let body = result
+            .syntax()
+            .descendants()
+            .find_map(JsFunctionBody::cast)
+            .unwrap();
+        let l_curly = body.l_curly_token().unwrap();
+
+        assert_eq!(l_curly.matching_bracket(), body.r_curly_token().ok());
+        assert_eq!(
+            body.r_curly_token().unwrap().matching_bracket(),
+            Some(l_curly)
+        );
+        assert_eq!(
+            body.r_curly_token().unwrap().text_trimmed_range().start(),
+            TextSize::from(code.len() as u32 - 1)
+        );
+    }
 }
@@ -6,8 +6,18 @@ use std::{ffi::OsStr, path::Path};
 ///
 /// Defaults to the latest stable ECMAScript standard.
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(
+    Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
 pub enum LanguageVersion {
+    ES2015,
+    ES2016,
+    ES2017,
+    ES2018,
+    ES2019,
+    ES2020,
+    ES2021,
     ES2022,
 
     /// The next, not yet finalized ECMAScript version
@@ -19,6 +29,20 @@ impl LanguageVersion {
     pub const fn latest() -> Self {
         LanguageVersion::ES2022
     }
+
+    /// Returns `true` if this version supports the given feature
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use biome_js_syntax::{EcmaFeature, LanguageVersion};
+    ///
+    /// assert!(!LanguageVersion::ES2019.supports(EcmaFeature::OptionalChaining));
+    /// assert!(LanguageVersion::ES2020.supports(EcmaFeature::OptionalChaining));
+    /// ```
+    pub fn supports(self, feature: EcmaFeature) -> bool {
+        self >= feature.minimum_version()
+    }
 }
 
 impl Default for LanguageVersion {
@@ -27,12 +51,65 @@ impl Default for LanguageVersion {
     }
 }
 
+/// ECMAScript features that were introduced after ES2015.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum EcmaFeature {
+    /// `a ** b`
+    ExponentiationOperator,
+    /// `async function f() { await p; }`
+    AsyncFunctions,
+    /// `for await (const x of xs) {}`
+    AsyncIteration,
+    /// `{ ...a }` and `const { a, ...rest } = b`
+    ObjectRestSpread,
+    /// `try {} catch {}`
+    OptionalCatchBinding,
+    /// `a?.b`
+    OptionalChaining,
+    /// `a ?? b`
+    NullishCoalescing,
+    /// `10n`
+    BigInt,
+    /// `a ||= b`, `a &&= b`, and `a ??= b`
+    LogicalAssignment,
+    /// `1_000_000`
+    NumericSeparators,
+    /// `class A { a = 1; #b = 2; }`
+    ClassFields,
+    /// `class A { static {} }`
+    ClassStaticBlocks,
+    /// `await` at the top level of a module
+    TopLevelAwait,
+}
+
+impl EcmaFeature {
+    /// Returns the first ECMAScript version that supports this feature
+    pub const fn minimum_version(self) -> LanguageVersion {
+        match self {
+            EcmaFeature::ExponentiationOperator => LanguageVersion::ES2016,
+            EcmaFeature::AsyncFunctions => LanguageVersion::ES2017,
+            EcmaFeature::AsyncIteration | EcmaFeature::ObjectRestSpread => LanguageVersion::ES2018,
+            EcmaFeature::OptionalCatchBinding => LanguageVersion::ES2019,
+            EcmaFeature::OptionalChaining
+            | EcmaFeature::NullishCoalescing
+            | EcmaFeature::BigInt => LanguageVersion::ES2020,
+            EcmaFeature::LogicalAssignment | EcmaFeature::NumericSeparators => {
+                LanguageVersion::ES2021
+            }
+            EcmaFeature::ClassFields
+            | EcmaFeature::ClassStaticBlocks
+            | EcmaFeature::TopLevelAwait => LanguageVersion::ES2022,
+        }
+    }
+}
+
 /// Is the source file an ECMAScript Module or Script.
 /// Changes the parsing semantic.
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema,))]
 #[derive(
     Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
 )]
+#[serde(rename_all = "lowercase")]
 pub enum ModuleKind {
     /// An ECMAScript [Script](https://tc39.es/ecma262/multipage/ecmascript-language-scripts-and-modules.html#sec-scripts)
     Script,
@@ -40,12 +117,18 @@ pub enum ModuleKind {
     /// An ECMAScript [Module](https://tc39.es/ecma262/multipage/ecmascript-language-scripts-and-modules.html#sec-modules)
     #[default]
     Module,
+
+    /// The source may be a script or a module. The parser detects the kind from the
+    /// source text, based on the presence of top-level `import` and `export` declarations.
+    Unknown,
 }
 
 impl ModuleKind {
+    /// Returns `true` for [ModuleKind::Script], and `false` for [ModuleKind::Unknown].
     pub const fn is_script(&self) -> bool {
         matches!(self, ModuleKind::Script)
     }
+    /// Returns `true` for [ModuleKind::Module], and `false` for [ModuleKind::Unknown].
     pub const fn is_module(&self) -> bool {
         matches!(self, ModuleKind::Module)
     }
@@ -55,6 +138,7 @@ impl ModuleKind {
 #[derive(
     Debug, Copy, Clone, Eq, PartialEq, Hash, Default, serde::Serialize, serde::Deserialize,
 )]
+#[serde(rename_all = "kebab-case")]
 pub enum LanguageVariant {
     /// Standard JavaScript or TypeScript syntax without any extensions
     #[default]
@@ -83,6 +167,7 @@ impl LanguageVariant {
 #[derive(
     Debug, Copy, Clone, Eq, PartialEq, Default, Hash, serde::Serialize, serde::Deserialize,
 )]
+#[serde(rename_all = "lowercase")]
 pub enum Language {
     #[default]
     JavaScript,
@@ -90,6 +175,11 @@ pub enum Language {
     /// TypeScript source with or without JSX.
     /// `definition_file` must be true for `d.ts` files.
     TypeScript { definition_file: bool },
+
+    /// JavaScript source with Flow type annotations, with or without JSX.
+    /// The annotations are parsed with the TypeScript type syntax, which covers the common
+    /// subset of both languages, such as parameter, return, and variable annotations.
+    Flow,
 }
 
 impl Language {
@@ -99,6 +189,9 @@ impl Language {
     pub const fn is_typescript(&self) -> bool {
         matches!(self, Language::TypeScript { .. })
     }
+    pub const fn is_flow(&self) -> bool {
+        matches!(self, Language::Flow)
+    }
 
     pub const fn is_definition_file(&self) -> bool {
         matches!(
@@ -113,6 +206,7 @@ impl Language {
 #[derive(
     Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
 )]
+#[serde(rename_all = "lowercase")]
 pub enum EmbeddingKind {
     Astro,
     Vue,
@@ -193,6 +287,11 @@ impl JsFileSource {
         }
     }
 
+    /// language: Flow, variant: Standard, module_kind: Module, version: Latest
+    pub fn flow() -> Self {
+        Self::js_module().with_language(Language::Flow)
+    }
+
     /// Astro file definition
     pub fn astro() -> Self {
         Self::ts().with_embedding_kind(EmbeddingKind::Astro)
@@ -208,6 +307,11 @@ impl JsFileSource {
         Self::js_module().with_embedding_kind(EmbeddingKind::Svelte)
     }
 
+    pub const fn with_language(mut self, language: Language) -> Self {
+        self.language = language;
+        self
+    }
+
     pub const fn with_module_kind(mut self, kind: ModuleKind) -> Self {
         self.module_kind = kind;
         self
@@ -256,19 +360,39 @@ impl JsFileSource {
         self.variant.is_jsx()
     }
 
+    /// Returns `true` for TypeScript definition files, such as `index.d.ts`, which may only
+    /// contain declarations.
+    pub const fn is_definition_file(&self) -> bool {
+        self.language.is_definition_file()
+    }
+
+    /// Returns `true` if the source may contain type annotations, which is the case for
+    /// TypeScript and Flow.
+    pub const fn can_have_types(&self) -> bool {
+        self.language.is_typescript() || self.language.is_flow()
+    }
+
+    /// Returns `true` if the source can be an executable entrypoint, which is where a hashbang
+    /// (`#!/usr/bin/env node`) is meaningful.
+    ///
+    /// TypeScript definition files only describe types and are never executed.
+    pub const fn is_executable(&self) -> bool {
+        !self.language.is_definition_file()
+    }
+
     pub const fn as_embedding_kind(&self) -> &EmbeddingKind {
         &self.embedding_kind
     }
 
     pub fn file_extension(&self) -> &str {
         match self.language {
-            Language::JavaScript => {
+            Language::JavaScript | Language::Flow => {
                 if matches!(self.variant, LanguageVariant::Jsx) {
                     return "jsx";
                 }
                 match self.module_kind {
                     ModuleKind::Script => "cjs",
-                    ModuleKind::Module => "js",
+                    ModuleKind::Module | ModuleKind::Unknown => "js",
                 }
             }
             Language::TypeScript { .. } => {
@@ -288,13 +412,18 @@ impl JsFileSource {
     }
 
     /// Try to return the JS file source corresponding to this file extension
+    ///
+    /// The returned file source targets [LanguageVersion::ESNext], so that files accept
+    /// the proposals Biome supports, such as decorators.
     pub fn try_from_extension(extension: &str) -> Result<Self, FileSourceError> {
-        match extension {
+        let file_source = match extension {
             "js" | "mjs" | "jsx" => Ok(Self::jsx()),
             "cjs" => Ok(Self::js_script()),
             "ts" => Ok(Self::ts()),
             "mts" | "cts" => Ok(Self::ts_restricted()),
             "tsx" => Ok(Self::tsx()),
+            // Note: like "d.ts", these extensions contain dots
+            "js.flow" | "flow.js" => Ok(Self::flow().with_variant(LanguageVariant::Jsx)),
             // Note: the extension passed to this function can contain dots,
             // this should be handled properly by the extension provider
             "d.ts" | "d.mts" | "d.cts" => Ok(Self::d_ts()),
@@ -308,7 +437,40 @@ impl JsFileSource {
                 Default::default(),
                 extension.into(),
             )),
+        }?;
+
+        Ok(file_source.with_version(LanguageVersion::ESNext))
+    }
+
+    /// Try to return the JS file source corresponding to this file name
+    ///
+    /// Well-known file names are checked first. Otherwise, the file source is inferred from
+    /// the extension, including the extensions of declaration files such as `index.d.ts`.
+    pub fn try_from_file_name(file_name: &str) -> Result<Self, FileSourceError> {
+        if let Ok(file_source) = Self::try_from_well_known(file_name) {
+            return Ok(file_source);
+        }
+
+        // We assume the file extensions are case-insensitive
+        // and we use the lowercase form of them for pattern matching
+        // TODO: This should be extracted to a dedicated function, maybe in biome_fs
+        // because the same logic is also used in DocumentFileSource::from_path_optional
+        // and we may support more and more extensions with more than one dots.
+        let lowercase_file_name = file_name.to_lowercase();
+        let extension = &match lowercase_file_name.as_str() {
+            name if name.ends_with(".d.ts") => Some("d.ts".to_owned()),
+            name if name.ends_with(".d.mts") => Some("d.mts".to_owned()),
+            name if name.ends_with(".d.cts") => Some("d.cts".to_owned()),
+            name if name.ends_with(".js.flow") => Some("js.flow".to_owned()),
+            name if name.ends_with(".flow.js") => Some("flow.js".to_owned()),
+            name => Path::new(name)
+                .extension()
+                .and_then(OsStr::to_str)
+                .map(str::to_owned),
         }
+        .ok_or_else(|| FileSourceError::MissingFileExtension(file_name.into()))?;
+
+        Self::try_from_extension(extension)
     }
 
     /// Try to return the JS file source corresponding to this language ID
@@ -317,8 +479,11 @@ impl JsFileSource {
     ///
     /// [LSP spec]: https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#textDocumentItem
     /// [VS Code spec]: https://code.visualstudio.com/docs/languages/identifiers
+    ///
+    /// Like [JsFileSource::try_from_extension], the returned file source targets
+    /// [LanguageVersion::ESNext].
     pub fn try_from_language_id(language_id: &str) -> Result<Self, FileSourceError> {
-        match language_id {
+        let file_source = match language_id {
             // We use Self::jsx() for the javascript language id
             // because `.js` files will be associated with the javascript language id
             // and we already use Self::jsx() for `.js` files in try_from_extension().
@@ -340,7 +505,9 @@ impl JsFileSource {
             // TODO: Remove once we have full support of svelte files
             "svelte" => Ok(Self::svelte()),
             _ => Err(FileSourceError::UnknownLanguageId(language_id.into())),
-        }
+        }?;
+
+        Ok(file_source.with_version(LanguageVersion::ESNext))
     }
 }
 
@@ -350,44 +517,256 @@ impl TryFrom<&Path> for JsFileSource {
     fn try_from(path: &Path) -> Result<Self, Self::Error> {
         let file_name = path
             .file_name()
-            .and_then(OsStr::to_str)
-            .ok_or_else(|| FileSourceError::MissingFileName(path.into()))?;
-
-        if let Ok(file_source) = Self::try_from_well_known(file_name) {
-            return Ok(file_source);
-        }
+            .ok_or_else(|| FileSourceError::MissingFileName(path.into()))?
+            .to_str()
+            .ok_or_else(|| FileSourceError::NonUtf8FileName(path.into()))?;
 
-        // We assume the file extensions are case-insensitive
-        // and we use the lowercase form of them for pattern matching
-        // TODO: This should be extracted to a dedicated function, maybe in biome_fs
-        // because the same logic is also used in DocumentFileSource::from_path_optional
-        // and we may support more and more extensions with more than one dots.
-        let extension = &match path {
-            _ if path
-                .to_str()
-                .is_some_and(|p| p.to_lowercase().ends_with(".d.ts")) =>
-            {
-                Some("d.ts".to_owned())
+        Self::try_from_file_name(file_name).map_err(|error| match error {
+            FileSourceError::MissingFileExtension(_) => {
+                FileSourceError::MissingFileExtension(path.into())
             }
-            _ if path
-                .to_str()
-                .is_some_and(|p| p.to_lowercase().ends_with(".d.mts")) =>
-            {
-                Some("d.mts".to_owned())
-            }
-            _ if path
-                .to_str()
-                .is_some_and(|p| p.to_lowercase().ends_with(".d.cts")) =>
-            {
-                Some("d.cts".to_owned())
+            error => error,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn language_version_supports() {
+        const VERSIONS: [LanguageVersion; 9] = [
+            LanguageVersion::ES2015,
+            LanguageVersion::ES2016,
+            LanguageVersion::ES2017,
+            LanguageVersion::ES2018,
+            LanguageVersion::ES2019,
+            LanguageVersion::ES2020,
+            LanguageVersion::ES2021,
+            LanguageVersion::ES2022,
+            LanguageVersion::ESNext,
+        ];
+        let cases = [
+            (EcmaFeature::ExponentiationOperator, LanguageVersion::ES2016),
+            (EcmaFeature::AsyncFunctions, LanguageVersion::ES2017),
+            (EcmaFeature::AsyncIteration, LanguageVersion::ES2018),
+            (EcmaFeature::ObjectRestSpread, LanguageVersion::ES2018),
+            (EcmaFeature::OptionalCatchBinding, LanguageVersion::ES2019),
+            (EcmaFeature::OptionalChaining, LanguageVersion::ES2020),
+            (EcmaFeature::NullishCoalescing, LanguageVersion::ES2020),
+            (EcmaFeature::BigInt, LanguageVersion::ES2020),
+            (EcmaFeature::LogicalAssignment, LanguageVersion::ES2021),
+            (EcmaFeature::NumericSeparators, LanguageVersion::ES2021),
+            (EcmaFeature::ClassFields, LanguageVersion::ES2022),
+            (EcmaFeature::ClassStaticBlocks, LanguageVersion::ES2022),
+            (EcmaFeature::TopLevelAwait, LanguageVersion::ES2022),
+        ];
+
+        for (feature, first_version) in cases {
+            for version in VERSIONS {
+                assert_eq!(
+                    version.supports(feature),
+                    version >= first_version,
+                    "{feature:?} in {version:?}"
+                );
             }
-            path => path
-                .extension()
-                .and_then(OsStr::to_str)
-                .map(|s| s.to_lowercase()),
         }
-        .ok_or_else(|| FileSourceError::MissingFileExtension(path.into()))?;
 
-        Self::try_from_extension(extension)
+        assert!(LanguageVersion::ES2019 < LanguageVersion::ES2022);
+        assert_eq!(LanguageVersion::default(), LanguageVersion::ES2022);
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let file_sources = [
+            JsFileSource::js_module(),
+            JsFileSource::js_script(),
+            JsFileSource::jsx(),
+            JsFileSource::ts(),
+            JsFileSource::ts_restricted(),
+            JsFileSource::tsx(),
+            JsFileSource::d_ts(),
+            JsFileSource::flow(),
+            JsFileSource::astro(),
+            JsFileSource::vue(),
+            JsFileSource::svelte(),
+            JsFileSource::js_module().with_version(LanguageVersion::ES2018),
+            JsFileSource::js_module().with_version(LanguageVersion::ESNext),
+        ];
+
+        for file_source in file_sources {
+            let json = serde_json::to_string(&file_source).unwrap();
+            let deserialized: JsFileSource = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized, file_source, "{json}");
+        }
+    }
+
+    #[test]
+    fn serialize_by_name() {
+        let json = serde_json::to_string(&JsFileSource::d_ts()).unwrap();
+        assert_eq!(
+            json,
+            r#"{"language":{"typescript":{"definition_file":true}},"variant":"standard","module_kind":"module","version":"es2022","embedding_kind":"none"}"#
+        );
+
+        let json =
+            serde_json::to_string(&JsFileSource::tsx().with_version(LanguageVersion::ESNext))
+                .unwrap();
+        assert_eq!(
+            json,
+            r#"{"language":{"typescript":{"definition_file":false}},"variant":"jsx","module_kind":"module","version":"esnext","embedding_kind":"none"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&LanguageVariant::StandardRestricted).unwrap(),
+            r#""standard-restricted""#
+        );
+    }
+
+    #[test]
+    fn try_from_path_with_known_extension() {
+        assert_eq!(
+            JsFileSource::try_from(Path::new("src/index.ts")).unwrap(),
+            JsFileSource::ts().with_version(LanguageVersion::ESNext)
+        );
+        assert_eq!(
+            JsFileSource::try_from(Path::new("src/App.tsx")).unwrap(),
+            JsFileSource::tsx().with_version(LanguageVersion::ESNext)
+        );
+        assert_eq!(
+            JsFileSource::try_from(Path::new("types/index.d.ts")).unwrap(),
+            JsFileSource::d_ts().with_version(LanguageVersion::ESNext)
+        );
+        assert_eq!(
+            JsFileSource::try_from(Path::new("src/index.js"))
+                .unwrap()
+                .version(),
+            LanguageVersion::ESNext
+        );
+    }
+
+    #[test]
+    fn with_language() {
+        let file_source = JsFileSource::js_module().with_language(Language::TypeScript {
+            definition_file: false,
+        });
+
+        assert_eq!(file_source, JsFileSource::ts());
+        assert!(file_source.language().is_typescript());
+        assert!(file_source.is_module());
+    }
+
+    #[test]
+    fn try_from_extension() {
+        let module = JsFileSource::try_from_extension("mjs").unwrap();
+        assert!(module.is_module());
+
+        let script = JsFileSource::try_from_extension("cjs").unwrap();
+        assert!(!script.is_module());
+    }
+
+    #[test]
+    fn try_from_file_name() {
+        assert_eq!(
+            JsFileSource::try_from_file_name("index.d.cts").unwrap(),
+            JsFileSource::d_ts().with_version(LanguageVersion::ESNext)
+        );
+        assert_eq!(
+            JsFileSource::try_from_file_name("INDEX.MTS").unwrap(),
+            JsFileSource::ts_restricted().with_version(LanguageVersion::ESNext)
+        );
+        assert!(matches!(
+            JsFileSource::try_from_file_name("LICENSE"),
+            Err(FileSourceError::MissingFileExtension(_))
+        ));
+        for file_name in ["index.js.flow", "index.flow.js"] {
+            let file_source = JsFileSource::try_from_file_name(file_name).unwrap();
+            assert!(file_source.language().is_flow(), "{file_name}");
+        }
+    }
+
+    #[test]
+    fn try_from_path_without_extension() {
+        assert!(matches!(
+            JsFileSource::try_from(Path::new("Makefile")),
+            Err(FileSourceError::MissingFileExtension(_))
+        ));
+        assert!(matches!(
+            JsFileSource::try_from(Path::new("/")),
+            Err(FileSourceError::MissingFileName(_))
+        ));
+    }
+
+    #[test]
+    fn capability_predicates() {
+        // (source, is_typescript, is_jsx, is_definition_file, can_have_types)
+        let cases = [
+            (JsFileSource::ts(), true, false, false, true),
+            (JsFileSource::tsx(), true, true, false, true),
+            (JsFileSource::d_ts(), true, false, true, true),
+            (JsFileSource::jsx(), false, true, false, false),
+            (JsFileSource::js_module(), false, false, false, false),
+        ];
+
+        for (source, is_typescript, is_jsx, is_definition_file, can_have_types) in cases {
+            assert_eq!(source.is_typescript(), is_typescript, "{source:?}");
+            assert_eq!(source.is_jsx(), is_jsx, "{source:?}");
+            assert_eq!(
+                source.is_definition_file(),
+                is_definition_file,
+                "{source:?}"
+            );
+            assert_eq!(source.can_have_types(), can_have_types, "{source:?}");
+        }
+
+        assert!(JsFileSource::flow().can_have_types());
+        assert!(!JsFileSource::flow().is_typescript());
+    }
+
+    #[test]
+    fn try_from_dotfile() {
+        assert!(matches!(
+            JsFileSource::try_from(Path::new(".babelrc")),
+            Err(FileSourceError::MissingFileExtension(_))
+        ));
+    }
+
+    #[test]
+    fn try_from_path_ending_with_dot() {
+        assert!(matches!(
+            JsFileSource::try_from(Path::new("index.")),
+            Err(FileSourceError::UnknownExtension(_, _))
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn try_from_non_utf8_path() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let path = Path::new(OsStr::from_bytes(b"index\xFF.ts"));
+        assert!(matches!(
+            JsFileSource::try_from(path),
+            Err(FileSourceError::NonUtf8FileName(_))
+        ));
+    }
+
+    #[test]
+    fn file_source_error_display() {
+        let error = JsFileSource::try_from(Path::new("/")).unwrap_err();
+        assert_eq!(error.to_string(), "The path \"/\" has no file name");
+
+        let error = JsFileSource::try_from(Path::new("Makefile")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The path \"Makefile\" has no file extension"
+        );
+
+        let error = FileSourceError::NonUtf8FileName(PathBuf::from("index.ts"));
+        assert_eq!(
+            error.to_string(),
+            "The file name of the path \"index.ts\" isn't valid UTF-8"
+        );
     }
 }
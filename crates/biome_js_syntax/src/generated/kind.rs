@@ -177,6 +177,7 @@ pub enum JsSyntaxKind {
     JS_MODULE_ITEM_LIST,
     JS_SCRIPT,
     JS_EXPRESSION_SNIPPED,
+    TS_TYPE_SNIPPED,
     JS_DIRECTIVE,
     JS_DIRECTIVE_LIST,
     JS_STATEMENT_LIST,
@@ -1208,6 +1208,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::TsTypeParameters::new_unchecked(node) };
                     $body
                 }
+                $crate::JsSyntaxKind::TS_TYPE_SNIPPED => {
+                    let $pattern = unsafe { $crate::TsTypeSnipped::new_unchecked(node) };
+                    $body
+                }
                 $crate::JsSyntaxKind::TS_TYPEOF_TYPE => {
                     let $pattern = unsafe { $crate::TsTypeofType::new_unchecked(node) };
                     $body
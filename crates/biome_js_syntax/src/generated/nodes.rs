@@ -13207,6 +13207,47 @@ pub struct TsTypeParametersFields {
     pub r_angle_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TsTypeSnipped {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TsTypeSnipped {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TsTypeSnippedFields {
+        TsTypeSnippedFields {
+            ty: self.ty(),
+            eof_token: self.eof_token(),
+        }
+    }
+    pub fn ty(&self) -> SyntaxResult<AnyTsType> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn eof_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for TsTypeSnipped {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct TsTypeSnippedFields {
+    pub ty: SyntaxResult<AnyTsType>,
+    pub eof_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsTypeofType {
     pub(crate) syntax: SyntaxNode,
 }
@@ -15095,6 +15136,7 @@ pub enum AnyJsRoot {
     JsExpressionSnipped(JsExpressionSnipped),
     JsModule(JsModule),
     JsScript(JsScript),
+    TsTypeSnipped(TsTypeSnipped),
 }
 impl AnyJsRoot {
     pub fn as_js_expression_snipped(&self) -> Option<&JsExpressionSnipped> {
@@ -15115,6 +15157,12 @@ impl AnyJsRoot {
             _ => None,
         }
     }
+    pub fn as_ts_type_snipped(&self) -> Option<&TsTypeSnipped> {
+        match &self {
+            AnyJsRoot::TsTypeSnipped(item) => Some(item),
+            _ => None,
+        }
+    }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
@@ -29145,6 +29193,45 @@ impl From<TsTypeParameters> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for TsTypeSnipped {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TS_TYPE_SNIPPED as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TS_TYPE_SNIPPED
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TsTypeSnipped {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TsTypeSnipped")
+            .field("ty", &support::DebugSyntaxResult(self.ty()))
+            .field("eof_token", &support::DebugSyntaxResult(self.eof_token()))
+            .finish()
+    }
+}
+impl From<TsTypeSnipped> for SyntaxNode {
+    fn from(n: TsTypeSnipped) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TsTypeSnipped> for SyntaxElement {
+    fn from(n: TsTypeSnipped) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
 impl AstNode for TsTypeofType {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -33792,19 +33879,29 @@ impl From<JsScript> for AnyJsRoot {
         AnyJsRoot::JsScript(node)
     }
 }
+impl From<TsTypeSnipped> for AnyJsRoot {
+    fn from(node: TsTypeSnipped) -> AnyJsRoot {
+        AnyJsRoot::TsTypeSnipped(node)
+    }
+}
 impl AstNode for AnyJsRoot {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = JsExpressionSnipped::KIND_SET
         .union(JsModule::KIND_SET)
-        .union(JsScript::KIND_SET);
+        .union(JsScript::KIND_SET)
+        .union(TsTypeSnipped::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, JS_EXPRESSION_SNIPPED | JS_MODULE | JS_SCRIPT)
+        matches!(
+            kind,
+            JS_EXPRESSION_SNIPPED | JS_MODULE | JS_SCRIPT | TS_TYPE_SNIPPED
+        )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
             JS_EXPRESSION_SNIPPED => AnyJsRoot::JsExpressionSnipped(JsExpressionSnipped { syntax }),
             JS_MODULE => AnyJsRoot::JsModule(JsModule { syntax }),
             JS_SCRIPT => AnyJsRoot::JsScript(JsScript { syntax }),
+            TS_TYPE_SNIPPED => AnyJsRoot::TsTypeSnipped(TsTypeSnipped { syntax }),
             _ => return None,
         };
         Some(res)
@@ -33814,6 +33911,7 @@ impl AstNode for AnyJsRoot {
             AnyJsRoot::JsExpressionSnipped(it) => &it.syntax,
             AnyJsRoot::JsModule(it) => &it.syntax,
             AnyJsRoot::JsScript(it) => &it.syntax,
+            AnyJsRoot::TsTypeSnipped(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
@@ -33821,6 +33919,7 @@ impl AstNode for AnyJsRoot {
             AnyJsRoot::JsExpressionSnipped(it) => it.syntax,
             AnyJsRoot::JsModule(it) => it.syntax,
             AnyJsRoot::JsScript(it) => it.syntax,
+            AnyJsRoot::TsTypeSnipped(it) => it.syntax,
         }
     }
 }
@@ -33830,6 +33929,7 @@ impl std::fmt::Debug for AnyJsRoot {
             AnyJsRoot::JsExpressionSnipped(it) => std::fmt::Debug::fmt(it, f),
             AnyJsRoot::JsModule(it) => std::fmt::Debug::fmt(it, f),
             AnyJsRoot::JsScript(it) => std::fmt::Debug::fmt(it, f),
+            AnyJsRoot::TsTypeSnipped(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -33839,6 +33939,7 @@ impl From<AnyJsRoot> for SyntaxNode {
             AnyJsRoot::JsExpressionSnipped(it) => it.into(),
             AnyJsRoot::JsModule(it) => it.into(),
             AnyJsRoot::JsScript(it) => it.into(),
+            AnyJsRoot::TsTypeSnipped(it) => it.into(),
         }
     }
 }
@@ -38702,6 +38803,11 @@ impl std::fmt::Display for TsTypeParameters {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for TsTypeSnipped {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for TsTypeofType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -5983,6 +5983,20 @@ impl TsTypeParameters {
         )
     }
 }
+impl TsTypeSnipped {
+    pub fn with_ty(self, element: AnyTsType) -> Self {
+        Self::unwrap_cast(
+            self.syntax
+                .splice_slots(0usize..=0usize, once(Some(element.into_syntax().into()))),
+        )
+    }
+    pub fn with_eof_token(self, element: SyntaxToken) -> Self {
+        Self::unwrap_cast(
+            self.syntax
+                .splice_slots(1usize..=1usize, once(Some(element.into()))),
+        )
+    }
+}
 impl TsTypeofType {
     pub fn with_typeof_token(self, element: SyntaxToken) -> Self {
         Self::unwrap_cast(
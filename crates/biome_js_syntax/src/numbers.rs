@@ -44,9 +44,27 @@ pub fn parse_js_number(num: &str) -> Option<f64> {
     }
 }
 
+/// The largest integer `n` such that `n` and `n + 1` are both exactly representable as a JS number.
+pub const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+/// Returns `true` if the number is an integer literal larger than [MAX_SAFE_INTEGER],
+/// which can't be represented exactly as a JS number.
+///
+/// Decimal literals with a fraction or an exponent aren't integer literals.
+pub fn exceeds_max_safe_integer(num: &str) -> bool {
+    let (radix, raw) = split_into_radix_and_number(num);
+
+    if radix == 10 && !raw.bytes().all(|byte| byte.is_ascii_digit()) {
+        return false;
+    }
+
+    // Literals that don't fit in a `u128` are far beyond the limit
+    u128::from_str_radix(&raw, radix as u32).map_or(true, |num| num > MAX_SAFE_INTEGER as u128)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::split_into_radix_and_number;
+    use super::{exceeds_max_safe_integer, split_into_radix_and_number};
     use biome_js_factory::syntax::{JsNumberLiteralExpression, JsSyntaxKind::*};
     use biome_js_factory::JsSyntaxTreeBuilder;
     use biome_rowan::AstNode;
@@ -146,4 +164,16 @@ mod tests {
         assert_split("12_34", 10, "1234");
         assert_split("12_34", 10, "1234");
     }
+
+    #[test]
+    fn max_safe_integer() {
+        assert!(!exceeds_max_safe_integer("9007199254740991"));
+        assert!(exceeds_max_safe_integer("9007199254740992"));
+        assert!(exceeds_max_safe_integer("9_007_199_254_740_993"));
+        assert!(exceeds_max_safe_integer("0x20000000000000"));
+        assert!(exceeds_max_safe_integer(&"9".repeat(50)));
+        assert!(!exceeds_max_safe_integer("9007199254740993.5"));
+        assert!(!exceeds_max_safe_integer("1e100"));
+        assert!(!exceeds_max_safe_integer("42"));
+    }
 }
@@ -3,13 +3,15 @@ use crate::Parser;
 use biome_diagnostics::console::fmt::Display;
 use biome_diagnostics::console::{markup, MarkupBuf};
 use biome_diagnostics::location::AsSpan;
-use biome_diagnostics::{Advices, Diagnostic, Location, LogCategory, MessageAndDescription, Visit};
-use biome_rowan::{SyntaxKind, TextLen, TextRange};
+use biome_diagnostics::{
+    Advices, Diagnostic, Location, LogCategory, MessageAndDescription, Severity, Visit,
+};
+use biome_rowan::{SyntaxKind, TextLen, TextRange, TextSize};
 use std::cmp::Ordering;
 
 /// A specialized diagnostic for the parser
 ///
-/// Parser diagnostics are always **errors**.
+/// Parser diagnostics are **errors** unless created with another severity, see [ParseDiagnostic::with_severity].
 ///
 /// A parser diagnostics structured in this way:
 /// 1. a mandatory message and a mandatory [TextRange]
@@ -19,7 +21,7 @@ use std::cmp::Ordering;
 /// These information **are printed in this exact order**.
 ///
 #[derive(Clone, Debug, Diagnostic)]
-#[diagnostic(category = "parse", severity = Error)]
+#[diagnostic(category = "parse")]
 pub struct ParseDiagnostic {
     /// The location where the error is occurred
     #[location(span)]
@@ -29,6 +31,8 @@ pub struct ParseDiagnostic {
     message: MessageAndDescription,
     #[advice]
     advice: ParserAdvice,
+    #[severity]
+    severity: Severity,
 }
 
 /// Possible details related to the diagnostic
@@ -118,6 +122,7 @@ impl ParseDiagnostic {
             span: span.as_span(),
             message: MessageAndDescription::from(markup! { {message} }.to_owned()),
             advice: ParserAdvice::default(),
+            severity: Severity::Error,
         }
     }
 
@@ -132,6 +137,7 @@ impl ParseDiagnostic {
             span: range.as_span(),
             message: MessageAndDescription::from(msg),
             advice: ParserAdvice::default(),
+            severity: Severity::Error,
         }
         .with_detail(range, format!("Expected {} here.", names))
     }
@@ -176,12 +182,33 @@ impl ParseDiagnostic {
             span: range.as_span(),
             message: MessageAndDescription::from(msg),
             advice: ParserAdvice::default(),
+            severity: Severity::Error,
         }
         .with_detail(range, format!("Expected {} here.", joined_names))
     }
 
     pub const fn is_error(&self) -> bool {
-        true
+        matches!(self.severity, Severity::Error | Severity::Fatal)
+    }
+
+    /// Moves the ranges of the diagnostic and of its details forward by `offset`.
+    pub fn with_offset(mut self, offset: TextSize) -> Self {
+        self.span = self.span.map(|span| span + offset);
+
+        for advice in &mut self.advice.advice_list {
+            if let ParserAdviceKind::Detail(detail) = advice {
+                detail.span = detail.span.map(|span| span + offset);
+            }
+        }
+
+        self
+    }
+
+    /// Changes the severity of the diagnostic, e.g. to report a warning that doesn't make the
+    /// parsed tree invalid.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
     }
 
     /// Use this API if you want to highlight more code frame, to help to explain where's the error.
@@ -529,3 +556,38 @@ pub fn merge_diagnostics(
         }
     }
 }
+
+/// Adds a single note telling how many diagnostics the parser dropped because it exceeded the
+/// maximum number of diagnostics, see [crate::ParserContext::with_max_diagnostics].
+///
+/// `diagnostics` may exceed `max` if the lexer diagnostics were merged into the parser diagnostics.
+/// The diagnostics after `max` are dropped as well and counted in the note.
+pub fn truncate_diagnostics(
+    diagnostics: &mut Vec<ParseDiagnostic>,
+    max: usize,
+    mut omitted: usize,
+    omitted_range: Option<TextRange>,
+) {
+    let mut span = omitted_range;
+
+    if diagnostics.len() > max {
+        omitted += diagnostics.len() - max;
+        span = span
+            .into_iter()
+            .chain(diagnostics[max].span)
+            .min_by_key(|range| range.start());
+        diagnostics.truncate(max);
+    }
+
+    if omitted == 0 {
+        return;
+    }
+
+    diagnostics.push(
+        ParseDiagnostic::new(
+            format!("Too many diagnostics, {omitted} more were truncated."),
+            span,
+        )
+        .with_severity(Severity::Information),
+    );
+}
@@ -502,6 +502,12 @@ impl<'l, Lex: Lexer<'l>> BufferedLexer<Lex::Kind, Lex> {
         self.inner.source()
     }
 
+    /// Returns the underlying [Lexer]
+    #[inline]
+    pub fn lexer(&self) -> &Lex {
+        &self.inner
+    }
+
     /// Rewinds the lexer to the state stored in the checkpoint.
     pub fn rewind(&mut self, checkpoint: LexerCheckpoint<Lex::Kind>) {
         self.inner.rewind(checkpoint);
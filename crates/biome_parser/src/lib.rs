@@ -33,6 +33,14 @@ pub struct ParserContext<K: SyntaxKind> {
     events: Vec<Event<K>>,
     skipping: bool,
     diagnostics: Vec<ParseDiagnostic>,
+    /// The maximum number of diagnostics to retain, see [ParserContext::with_max_diagnostics].
+    max_diagnostics: Option<usize>,
+    /// The number of diagnostics that were dropped because `max_diagnostics` was exceeded.
+    omitted_diagnostics: usize,
+    /// The range of the first dropped diagnostic.
+    first_omitted_range: Option<TextRange>,
+    /// The start of the last dropped diagnostic, to not count diagnostics at the same position twice.
+    last_omitted_start: Option<TextSize>,
 }
 
 impl<K: SyntaxKind> Default for ParserContext<K> {
@@ -47,9 +55,20 @@ impl<K: SyntaxKind> ParserContext<K> {
             skipping: false,
             events: Vec::new(),
             diagnostics: Vec::new(),
+            max_diagnostics: None,
+            omitted_diagnostics: 0,
+            first_omitted_range: None,
+            last_omitted_start: None,
         }
     }
 
+    /// Retains at most `max` diagnostics. Further diagnostics are only counted,
+    /// see [ParserContext::omitted_diagnostics]. `None` retains all diagnostics.
+    pub fn with_max_diagnostics(mut self, max: Option<usize>) -> Self {
+        self.max_diagnostics = max;
+        self
+    }
+
     /// Returns the slice with the parse events
     pub fn events(&self) -> &[Event<K>] {
         &self.events
@@ -60,9 +79,61 @@ impl<K: SyntaxKind> ParserContext<K> {
         &self.diagnostics
     }
 
-    /// Drops all diagnostics after `at`.
+    /// Returns the number of reported diagnostics, including the ones that were dropped because
+    /// the maximum number of diagnostics was exceeded.
+    pub fn diagnostics_count(&self) -> usize {
+        self.diagnostics.len() + self.omitted_diagnostics
+    }
+
+    /// Returns the number of diagnostics that were dropped because the maximum number of
+    /// diagnostics was exceeded, and the range of the first dropped diagnostic.
+    pub fn omitted_diagnostics(&self) -> (usize, Option<TextRange>) {
+        (self.omitted_diagnostics, self.first_omitted_range)
+    }
+
+    /// Adds a diagnostic unless the last diagnostic is at the same position. The diagnostic is
+    /// only counted if the maximum number of diagnostics is exceeded.
+    pub fn push_diagnostic(&mut self, diagnostic: ParseDiagnostic) {
+        let previous_start = if self.omitted_diagnostics > 0 {
+            self.last_omitted_start
+        } else {
+            self.diagnostics
+                .last()
+                .and_then(|previous| previous.diagnostic_range())
+                .map(|range| range.start())
+        };
+        let range = diagnostic.diagnostic_range().copied();
+
+        if let (Some(range), Some(previous_start)) = (range, previous_start) {
+            if range.start() == previous_start {
+                return;
+            }
+        }
+
+        if self
+            .max_diagnostics
+            .is_some_and(|max| self.diagnostics.len() >= max)
+        {
+            if self.omitted_diagnostics == 0 {
+                self.first_omitted_range = range;
+            }
+            self.omitted_diagnostics += 1;
+            self.last_omitted_start = range.map(|range| range.start());
+        } else {
+            self.diagnostics.push(diagnostic);
+        }
+    }
+
+    /// Drops all diagnostics after `at`, where `at` is a [ParserContext::diagnostics_count].
     pub fn truncate_diagnostics(&mut self, at: usize) {
-        self.diagnostics.truncate(at);
+        if at <= self.diagnostics.len() {
+            self.diagnostics.truncate(at);
+            self.omitted_diagnostics = 0;
+            self.first_omitted_range = None;
+        } else {
+            self.omitted_diagnostics = self.omitted_diagnostics.min(at - self.diagnostics.len());
+        }
+        self.last_omitted_start = None;
     }
 
     /// Pushes a new token event
@@ -107,7 +178,7 @@ impl<K: SyntaxKind> ParserContext<K> {
             errors_pos,
         } = checkpoint;
         self.drain_events(self.cur_event_pos() - event_pos);
-        self.diagnostics.truncate(errors_pos as usize);
+        self.truncate_diagnostics(errors_pos as usize);
     }
 
     /// Get a checkpoint representing the progress of the parser at this point of time
@@ -115,7 +186,7 @@ impl<K: SyntaxKind> ParserContext<K> {
     pub fn checkpoint(&self) -> ParserContextCheckpoint {
         ParserContextCheckpoint {
             event_pos: self.cur_event_pos(),
-            errors_pos: self.diagnostics.len() as u32,
+            errors_pos: self.diagnostics_count() as u32,
         }
     }
 
@@ -441,19 +512,7 @@ pub trait Parser: Sized {
     /// Add a diagnostic
     fn error(&mut self, err: impl ToDiagnostic<Self>) {
         let err = err.into_diagnostic(self);
-
-        // Don't report another diagnostic if the last diagnostic is at the same position of the current one
-        if let Some(previous) = self.context().diagnostics.last() {
-            match (&err.diagnostic_range(), &previous.diagnostic_range()) {
-                (Some(err_range), Some(previous_range))
-                    if err_range.start() == previous_range.start() =>
-                {
-                    return;
-                }
-                _ => {}
-            }
-        }
-        self.context_mut().diagnostics.push(err)
+        self.context_mut().push_diagnostic(err)
     }
 
     /// Creates a new diagnostic. Pass the message and the range where the error occurred
@@ -600,7 +659,7 @@ pub trait SyntaxFeature: Sized {
         if self.is_supported(p) {
             parse(p)
         } else {
-            let diagnostics_checkpoint = p.context().diagnostics().len();
+            let diagnostics_checkpoint = p.context().diagnostics_count();
             let syntax = parse(p);
             p.context_mut().truncate_diagnostics(diagnostics_checkpoint);
 
@@ -153,7 +153,7 @@ impl CompletedMarker {
         self.change_kind(p, self.kind(p).to_bogus());
     }
 
-    /// Get the range of the marker
+    /// Get the range of the marker, excluding the leading and trailing trivia
     pub fn range<P>(&self, p: &P) -> TextRange
     where
         P: Parser,
@@ -236,6 +236,7 @@ impl CompletedMarker {
         Marker::new(self.start_pos, self.offset)
     }
 
+    /// Get the kind of the marker, reading it from the parser's events without building the node
     pub fn kind<P>(&self, p: &P) -> P::Kind
     where
         P: Parser,
@@ -125,8 +125,19 @@ pub trait ParseRecovery {
     /// Checks if the parser is in a recovered state.
     fn is_at_recovered(&self, p: &mut Self::Parser<'_>) -> bool;
 
+    /// Additional tokens at which the recovery stops, for example a `}` or a custom sentinel.
+    ///
+    /// The boundary extends [ParseRecovery::is_at_recovered] rather than replacing it: the
+    /// recovery stops at the first token that is either recovered or in the boundary set, and it
+    /// always stops at the EOF. Returns an empty set by default, which recovers only at the
+    /// points reported by [ParseRecovery::is_at_recovered].
+    fn recovery_boundary(&self) -> TokenSet<Self::Kind> {
+        TokenSet::EMPTY
+    }
+
     /// Tries to recover by parsing all tokens into an `Bogus*` node until the parser finds any token
-    /// specified in the recovery set, the EOF, or a line break (depending on configuration).
+    /// specified in the recovery set or the [recovery boundary](ParseRecovery::recovery_boundary),
+    /// the EOF, or a line break (depending on configuration).
     /// Returns `Ok(bogus_node)` if recovery was successful, and `Err(RecoveryError::Eof)` if the parser
     /// is at the end of the file (before starting recovery).
     fn recover(&self, p: &mut Self::Parser<'_>) -> RecoveryResult {
@@ -134,7 +145,9 @@ pub trait ParseRecovery {
             return Err(RecoveryError::Eof);
         }
 
-        if self.is_at_recovered(p) {
+        let boundary = self.recovery_boundary();
+
+        if self.is_at_recovered(p) || p.at_ts(boundary) {
             return Err(RecoveryError::AlreadyRecovered);
         }
 
@@ -144,7 +157,7 @@ pub trait ParseRecovery {
 
         let m = p.start();
 
-        while !(self.is_at_recovered(p) || p.at(Self::Kind::EOF)) {
+        while !(self.is_at_recovered(p) || p.at_ts(boundary) || p.at(Self::Kind::EOF)) {
             p.bump_any();
         }
 
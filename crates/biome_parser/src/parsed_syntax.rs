@@ -312,6 +312,34 @@ impl ParsedSyntax {
             },
         }
     }
+
+    /// Returns this Syntax if it is present in the source text or recovers the parser like
+    /// [ParsedSyntax::or_recover] if the syntax is absent.
+    ///
+    /// Unlike [ParsedSyntax::or_recover], this always returns a node. If the recovery fails
+    /// because the parser is at the EOF, is already at a recovered position, or parses
+    /// speculatively, it inserts an empty [ParseRecovery::RECOVERED_KIND] node at the current
+    /// position instead. Either way, the error is added to the parsing diagnostics: its range
+    /// covers the skipped tokens, or the current token if the recovery failed.
+    pub fn or_recover_with<'source, P, E, R>(
+        self,
+        p: &mut P,
+        recovery: &R,
+        error_builder: E,
+    ) -> CompletedMarker
+    where
+        P: Parser,
+        R: ParseRecovery<Kind = P::Kind, Parser<'source> = P>,
+        E: FnOnce(&P, TextRange) -> ParseDiagnostic,
+    {
+        match self.or_recover(p, recovery, error_builder) {
+            Ok(syntax) => syntax,
+            Err(_) => {
+                let m = p.start();
+                m.complete(p, R::RECOVERED_KIND)
+            }
+        }
+    }
 }
 
 impl From<CompletedMarker> for ParsedSyntax {
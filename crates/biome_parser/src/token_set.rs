@@ -18,6 +18,61 @@ impl<K: SyntaxKind> TokenSet<K> {
         )
     }
 
+    /// Returns the kinds that are in both `self` and `other`.
+    ///
+    /// ```
+    /// use biome_parser::{token_set, TokenSet};
+    /// use biome_rowan::raw_language::RawLanguageKind::{self, *};
+    ///
+    /// let a: TokenSet<RawLanguageKind> = token_set![FOR_KW, L_PAREN_TOKEN];
+    /// let b = token_set![L_PAREN_TOKEN, R_PAREN_TOKEN];
+    /// assert_eq!(a.intersection(b), token_set![L_PAREN_TOKEN]);
+    /// ```
+    pub const fn intersection(self, other: TokenSet<K>) -> Self {
+        TokenSet(
+            [self.0[0] & other.0[0], self.0[1] & other.0[1]],
+            PhantomData,
+        )
+    }
+
+    /// Returns the kinds that are in `self` but not in `other`.
+    ///
+    /// ```
+    /// use biome_parser::{token_set, TokenSet};
+    /// use biome_rowan::raw_language::RawLanguageKind::{self, *};
+    ///
+    /// let a: TokenSet<RawLanguageKind> = token_set![FOR_KW, L_PAREN_TOKEN];
+    /// let b = token_set![L_PAREN_TOKEN, R_PAREN_TOKEN];
+    /// let c = token_set![FOR_KW, SEMICOLON_TOKEN];
+    /// assert_eq!(a.difference(b), token_set![FOR_KW]);
+    ///
+    /// // a \ (b ∪ c) = (a \ b) ∩ (a \ c)
+    /// assert_eq!(a.difference(b.union(c)), a.difference(b).intersection(a.difference(c)));
+    /// // a \ (b ∩ c) = (a \ b) ∪ (a \ c)
+    /// assert_eq!(a.difference(b.intersection(c)), a.difference(b).union(a.difference(c)));
+    /// ```
+    pub const fn difference(self, other: TokenSet<K>) -> Self {
+        TokenSet(
+            [self.0[0] & !other.0[0], self.0[1] & !other.0[1]],
+            PhantomData,
+        )
+    }
+
+    /// Returns `true` if every kind of `other` is in `self`.
+    ///
+    /// ```
+    /// use biome_parser::{token_set, TokenSet};
+    /// use biome_rowan::raw_language::RawLanguageKind::{self, *};
+    ///
+    /// let a: TokenSet<RawLanguageKind> = token_set![FOR_KW, L_PAREN_TOKEN];
+    /// assert!(a.contains_all(&token_set![FOR_KW]));
+    /// assert!(a.contains_all(&TokenSet::EMPTY));
+    /// assert!(!a.contains_all(&token_set![FOR_KW, R_PAREN_TOKEN]));
+    /// ```
+    pub const fn contains_all(&self, other: &TokenSet<K>) -> bool {
+        other.0[0] & !self.0[0] == 0 && other.0[1] & !self.0[1] == 0
+    }
+
     pub fn contains(&self, kind: K) -> bool {
         let kind = kind.to_raw().0;
         let num = kind as usize;
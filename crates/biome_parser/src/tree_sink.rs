@@ -42,6 +42,11 @@ where
     /// Signal that the sink must generate an EOF token when its finishing. See [LosslessTreeSink::finish] for more details.
     needs_eof: bool,
     trivia_pieces: Vec<TriviaPiece>,
+    /// `false` if the trivia is dropped instead of attached to the tokens, see [LossyTreeSink].
+    keep_trivia: bool,
+    /// The length of the text added to the tree if the trivia is dropped.
+    lossy_len: TextSize,
+    source_map: LossySourceMap,
 }
 
 impl<'a, L, Factory> TreeSink for LosslessTreeSink<'a, L, Factory>
@@ -81,32 +86,33 @@ where
     Factory: SyntaxFactory<Kind = L::Kind>,
 {
     pub fn new(text: &'a str, trivia: &'a [Trivia]) -> Self {
-        Self {
-            text,
-            trivia_list: trivia,
-            text_pos: 0.into(),
-            trivia_pos: 0,
-            parents_count: 0,
-            inner: TreeBuilder::default(),
-            errors: vec![],
-            needs_eof: true,
-            trivia_pieces: Vec::with_capacity(128),
-        }
+        Self::with_builder(text, trivia, TreeBuilder::default())
     }
 
     /// Reusing `NodeCache` between different [LosslessTreeSink]s saves memory.
     /// It allows to structurally share underlying trees.
     pub fn with_cache(text: &'a str, trivia: &'a [Trivia], cache: &'a mut NodeCache) -> Self {
+        Self::with_builder(text, trivia, TreeBuilder::with_cache(cache))
+    }
+
+    fn with_builder(
+        text: &'a str,
+        trivia: &'a [Trivia],
+        builder: TreeBuilder<'a, L, Factory>,
+    ) -> Self {
         Self {
             text,
             trivia_list: trivia,
             text_pos: 0.into(),
             trivia_pos: 0,
             parents_count: 0,
-            inner: TreeBuilder::with_cache(cache),
+            inner: builder,
             errors: vec![],
             needs_eof: true,
             trivia_pieces: Vec::with_capacity(128),
+            keep_trivia: true,
+            lossy_len: 0.into(),
+            source_map: LossySourceMap::default(),
         }
     }
 
@@ -129,6 +135,7 @@ where
         // Every trivia up to the token (including line breaks) will be the leading trivia
         self.eat_trivia(false);
         let trailing_start = self.trivia_pieces.len();
+        let text_start = self.text_pos;
 
         self.text_pos = token_end;
 
@@ -136,13 +143,23 @@ where
         // will be the trailing trivia...
         self.eat_trivia(true);
 
-        let token_range = TextRange::new(token_start, self.text_pos);
+        if self.keep_trivia {
+            let token_range = TextRange::new(token_start, self.text_pos);
 
-        let text = &self.text[token_range];
-        let leading = &self.trivia_pieces[0..trailing_start];
-        let trailing = &self.trivia_pieces[trailing_start..];
+            let text = &self.text[token_range];
+            let leading = &self.trivia_pieces[0..trailing_start];
+            let trailing = &self.trivia_pieces[trailing_start..];
 
-        self.inner.token_with_trivia(kind, text, leading, trailing);
+            self.inner.token_with_trivia(kind, text, leading, trailing);
+        } else {
+            self.source_map
+                .token_starts
+                .push((self.lossy_len, text_start));
+            self.lossy_len += token_end - text_start;
+
+            self.inner
+                .token(kind, &self.text[TextRange::new(text_start, token_end)]);
+        }
         self.trivia_pieces.clear();
     }
 
@@ -160,3 +177,111 @@ where
         }
     }
 }
+
+/// Structure for converting events to a syntax tree representation, discarding all trivia.
+///
+/// `LossyTreeSink` skips over the whitespace and comments between tokens instead of attaching them
+/// to the tokens. Because the tree doesn't store the trivia, the text ranges of its nodes don't
+/// match the ranges in the original source. Use the [LossySourceMap] returned by
+/// [LossyTreeSink::finish] to map them back to the original source.
+#[derive(Debug)]
+pub struct LossyTreeSink<'a, L, Factory>
+where
+    L: Language,
+    Factory: SyntaxFactory<Kind = L::Kind>,
+{
+    inner: LosslessTreeSink<'a, L, Factory>,
+}
+
+impl<'a, L, Factory> TreeSink for LossyTreeSink<'a, L, Factory>
+where
+    L: Language,
+    Factory: SyntaxFactory<Kind = L::Kind>,
+{
+    type Kind = L::Kind;
+
+    fn token(&mut self, kind: L::Kind, end: TextSize) {
+        self.inner.token(kind, end);
+    }
+
+    fn start_node(&mut self, kind: L::Kind) {
+        self.inner.start_node(kind);
+    }
+
+    fn finish_node(&mut self) {
+        self.inner.finish_node();
+    }
+
+    fn errors(&mut self, errors: Vec<ParseDiagnostic>) {
+        self.inner.errors(errors);
+    }
+}
+
+impl<'a, L, Factory> LossyTreeSink<'a, L, Factory>
+where
+    L: Language,
+    Factory: SyntaxFactory<Kind = L::Kind>,
+{
+    pub fn new(text: &'a str, trivia: &'a [Trivia]) -> Self {
+        Self::from_lossless(LosslessTreeSink::new(text, trivia))
+    }
+
+    /// Reusing `NodeCache` between different [LossyTreeSink]s saves memory.
+    /// It allows to structurally share underlying trees.
+    pub fn with_cache(text: &'a str, trivia: &'a [Trivia], cache: &'a mut NodeCache) -> Self {
+        Self::from_lossless(LosslessTreeSink::with_cache(text, trivia, cache))
+    }
+
+    fn from_lossless(mut inner: LosslessTreeSink<'a, L, Factory>) -> Self {
+        inner.keep_trivia = false;
+        Self { inner }
+    }
+
+    /// Finishes the tree and return the root node with possible parser errors, and the map
+    /// from the ranges in the tree to the ranges in the original source.
+    ///
+    /// If tree is finished without a [biome_rowan::SyntaxKind::EOF], one will be generated.
+    pub fn finish(self) -> (SyntaxNode<L>, Vec<ParseDiagnostic>, LossySourceMap) {
+        let source_map = self.inner.source_map;
+        (self.inner.inner.finish(), self.inner.errors, source_map)
+    }
+}
+
+/// Maps the offsets in a tree built by a [LossyTreeSink] to the offsets in the original source.
+#[derive(Debug, Clone, Default)]
+pub struct LossySourceMap {
+    /// The start of every token in the tree, together with its start in the original source.
+    token_starts: Vec<(TextSize, TextSize)>,
+}
+
+impl LossySourceMap {
+    /// Returns the offset in the original source of the `offset` in the tree.
+    pub fn original_offset(&self, offset: TextSize) -> TextSize {
+        let index = self
+            .token_starts
+            .partition_point(|(start, _)| *start <= offset);
+
+        match index.checked_sub(1) {
+            Some(index) => {
+                let (start, original_start) = self.token_starts[index];
+                original_start + (offset - start)
+            }
+            None => offset,
+        }
+    }
+
+    /// Returns the range in the original source of the `range` in the tree, for example
+    /// of a node's `text_range()`.
+    pub fn original_range(&self, range: TextRange) -> TextRange {
+        let start = self.original_offset(range.start());
+
+        if range.is_empty() {
+            TextRange::empty(start)
+        } else {
+            // The end is exclusive. Map the last byte of the range instead, the end could be the
+            // start of the next token that is preceded by trivia.
+            let last = self.original_offset(range.end() - TextSize::from(1));
+            TextRange::new(start, last + TextSize::from(1))
+        }
+    }
+}
@@ -54,6 +54,18 @@ impl SyntaxNode {
         SyntaxNode::new_root(self.green().into())
     }
 
+    pub fn clone_subtree_at(&self, offset: TextSize) -> SyntaxNode {
+        SyntaxNode {
+            ptr: NodeData::new(
+                NodeKind::Root {
+                    green: GreenElement::Node(self.green().into()),
+                },
+                0,
+                offset,
+            ),
+        }
+    }
+
     #[inline]
     pub(super) fn data(&self) -> &NodeData {
         self.ptr.as_ref()
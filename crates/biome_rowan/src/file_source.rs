@@ -8,6 +8,8 @@ pub enum FileSourceError {
     MissingFileName(PathBuf),
     /// The path has no file extension
     MissingFileExtension(PathBuf),
+    /// The file name of the path isn't valid UTF-8
+    NonUtf8FileName(PathBuf),
     /// The source type is unknown
     UnknownExtension(String, String),
     /// The file name is unknown (not a well-known file name)
@@ -27,6 +29,9 @@ impl Display for FileSourceError {
             FileSourceError::MissingFileExtension(path) => {
                 write!(f, "The path {path:?} has no file extension")
             }
+            FileSourceError::NonUtf8FileName(path) => {
+                write!(f, "The file name of the path {path:?} isn't valid UTF-8")
+            }
             FileSourceError::UnknownExtension(_, extension) => {
                 write!(f, "The parser can't parse the extension '{extension}' yet")
             }
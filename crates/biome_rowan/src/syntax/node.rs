@@ -410,6 +410,14 @@ impl<L: Language> SyntaxNode<L> {
         SyntaxNode::from(self.raw.clone_subtree())
     }
 
+    /// Returns an independent copy of the subtree rooted at this node, starting at `offset`.
+    ///
+    /// This is useful for trees of source fragments embedded in a larger document, so that the
+    /// ranges of the tree are expressed relative to the start of the document.
+    pub fn clone_subtree_at(&self, offset: TextSize) -> SyntaxNode<L> {
+        SyntaxNode::from(self.raw.clone_subtree_at(offset))
+    }
+
     /// Return a new version of this node detached from its parent node
     #[must_use = "syntax elements are immutable, the result of update methods must be propagated to have any effect"]
     pub fn detach(self) -> Self {
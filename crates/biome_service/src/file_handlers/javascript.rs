@@ -208,6 +208,7 @@ fn parse(
         biome_path,
         JsParserOptions {
             parse_class_parameter_decorators: parser_settings.parse_class_parameter_decorators,
+            ..JsParserOptions::default()
         },
     );
     let file_source = file_source.to_js_file_source().unwrap_or_default();
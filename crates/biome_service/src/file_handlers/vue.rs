@@ -11,7 +11,9 @@ use crate::WorkspaceError;
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
 use biome_js_parser::{parse_js_with_cache, JsParserOptions};
-use biome_js_syntax::{EmbeddingKind, JsFileSource, Language, TextRange, TextSize};
+use biome_js_syntax::{
+    EmbeddingKind, JsFileSource, Language, LanguageVersion, TextRange, TextSize,
+};
 use biome_parser::AnyParse;
 use biome_rowan::NodeCache;
 use lazy_static::lazy_static;
@@ -79,9 +81,13 @@ impl VueFileHandler {
                     Language::TypeScript { .. } => {
                         Some(JsFileSource::ts().with_embedding_kind(EmbeddingKind::Vue))
                     }
+                    Language::Flow => {
+                        Some(JsFileSource::flow().with_embedding_kind(EmbeddingKind::Vue))
+                    }
                 }
             })
             .map_or(JsFileSource::js_module(), |fs| fs)
+            .with_version(LanguageVersion::ESNext)
     }
 }
 
@@ -1927,21 +1927,31 @@ export interface JsonFileSource {
 export interface CssFileSource {
 	variant: CssVariant;
 }
-export type EmbeddingKind = "Astro" | "Vue" | "Svelte" | "None";
+export type EmbeddingKind = "astro" | "vue" | "svelte" | "none";
 export type Language =
-	| "JavaScript"
-	| { TypeScript: { definition_file: boolean } };
+	| "javascript"
+	| { typescript: { definition_file: boolean } }
+	| "flow";
 /**
  * Is the source file an ECMAScript Module or Script. Changes the parsing semantic.
  */
-export type ModuleKind = "Script" | "Module";
-export type LanguageVariant = "Standard" | "StandardRestricted" | "Jsx";
+export type ModuleKind = "script" | "module" | "unknown";
+export type LanguageVariant = "standard" | "standard-restricted" | "jsx";
 /**
 	* Enum of the different ECMAScript standard versions. The versions are ordered in increasing order; The newest version comes last.
 
 Defaults to the latest stable ECMAScript standard. 
 	 */
-export type LanguageVersion = "ES2022" | "ESNext";
+export type LanguageVersion =
+	| "es2015"
+	| "es2016"
+	| "es2017"
+	| "es2018"
+	| "es2019"
+	| "es2020"
+	| "es2021"
+	| "es2022"
+	| "esnext";
 /**
 	* The style of CSS contained in the file.
 
@@ -1,6 +1,8 @@
+use biome_js_parser::{parse_module, parse_validate, JsParserOptions};
+use biome_js_syntax::JsFileSource;
 use std::collections::HashMap;
 use xtask_bench::{bench_parser_group, TestCase};
-use xtask_bench::{criterion_group, criterion_main, Criterion};
+use xtask_bench::{black_box, criterion_group, criterion_main, Criterion, Throughput};
 #[cfg(target_os = "windows")]
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
@@ -37,5 +39,23 @@ fn bench_js_parser(criterion: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(js_parser, bench_js_parser);
+fn bench_js_validate(criterion: &mut Criterion) {
+    // A syntax error at the start of a large file
+    let code = format!(
+        "let a = ;\n{}",
+        "function f(a, b) { return a + b * 2; }\n".repeat(10_000)
+    );
+
+    let mut group = criterion.benchmark_group("js_validate");
+    group.throughput(Throughput::Bytes(code.len() as u64));
+    group.bench_function("parse_module", |b| {
+        b.iter(|| black_box(parse_module(&code, JsParserOptions::default())))
+    });
+    group.bench_function("parse_validate", |b| {
+        b.iter(|| black_box(parse_validate(&code, JsFileSource::js_module())))
+    });
+    group.finish();
+}
+
+criterion_group!(js_parser, bench_js_parser, bench_js_validate);
 criterion_main!(js_parser);